@@ -0,0 +1,657 @@
+use std::{
+    collections::HashSet,
+    ops::{Index, IndexMut},
+};
+
+pub type Position = (usize, usize);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Map<T> {
+    inner: Vec<Vec<T>>,
+}
+
+impl<T> Map<T> {
+    fn get_neighbor_in_direction(&self, pos: Position, direction: Direction) -> Option<Position> {
+        let change = match direction {
+            Direction::Up => (-1, 0),
+            Direction::Left => (0, -1),
+            Direction::Down => (1, 0),
+            Direction::Right => (0, 1),
+        };
+        if pos.0 == 0 && change.0 == -1
+            || pos.1 == 0 && change.1 == -1
+            || pos.0 == self.m() - 1 && change.0 == 1
+            || pos.1 == self.n() - 1 && change.1 == 1
+        {
+            return None;
+        }
+        Some((
+            (pos.0 as isize + change.0) as usize,
+            (pos.1 as isize + change.1) as usize,
+        ))
+    }
+
+    pub fn m(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn n(&self) -> usize {
+        self.inner[0].len()
+    }
+
+    pub fn iter_with_positions(&self) -> MapIterator<'_, T> {
+        MapIterator {
+            map_ref: self,
+            current_pos: (0, 0),
+        }
+    }
+}
+
+pub struct MapIterator<'a, T> {
+    map_ref: &'a Map<T>,
+    current_pos: Position,
+}
+
+impl<'a, T> Iterator for MapIterator<'a, T> {
+    type Item = (Position, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_pos.0 == self.map_ref.m() {
+            return None;
+        }
+        let pos = self.current_pos;
+        self.current_pos.1 += 1;
+        if self.current_pos.1 == self.map_ref.n() {
+            self.current_pos.1 = 0;
+            self.current_pos.0 += 1;
+        }
+        Some((pos, &self.map_ref[pos]))
+    }
+}
+
+impl<T> Index<Position> for Map<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        &self.inner[pos.0][pos.1]
+    }
+}
+
+impl<T> IndexMut<Position> for Map<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        &mut self.inner[pos.0][pos.1]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighborhood {
+    Four,
+    // Also the diagonals
+    Eight,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrailRules {
+    pub start: u32,
+    pub goal: u32,
+    // The height differences allowed in a single step
+    pub deltas: Vec<i64>,
+    pub neighborhood: Neighborhood,
+}
+
+impl Default for TrailRules {
+    fn default() -> Self {
+        TrailRules {
+            start: 0,
+            goal: 9,
+            deltas: vec![1],
+            neighborhood: Neighborhood::Four,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeightEncoding {
+    // A single digit per cell
+    Digits,
+    // A letter per cell, from `a` as 0 to `z` as 25
+    Letters,
+    // Numbers separated by whitespace, so they can have several digits
+    Numbers,
+}
+
+// A cell that doesn't hold a height in the encoding of the map
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidHeight {
+    pub position: Position,
+    pub found: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RulesError {
+    // Without any step, no trail can leave its start
+    NoDeltas,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trailhead {
+    pub position: Position,
+    // How many different goal cells can be reached
+    pub score: usize,
+    // How many different trails lead to a goal cell. Only counted when every
+    // step goes up, otherwise there can be far too many trails, see
+    // `count_trails`
+    pub rating: Option<usize>,
+}
+
+pub fn day10_part1(input: &str) -> usize {
+    let map = read_input(input).unwrap();
+    get_trailheads(&map)
+        .iter()
+        .map(|trailhead| trailhead.score)
+        .sum()
+}
+
+pub fn day10_part2(input: &str) -> usize {
+    let map = read_input(input).unwrap();
+    get_trailheads(&map)
+        .iter()
+        .map(|trailhead| trailhead.rating.unwrap())
+        .sum()
+}
+
+pub fn get_trailheads(map: &Map<u32>) -> Vec<Trailhead> {
+    get_trailheads_with_rules(map, &TrailRules::default()).unwrap()
+}
+
+pub fn get_trailheads_with_rules(
+    map: &Map<u32>,
+    rules: &TrailRules,
+) -> Result<Vec<Trailhead>, RulesError> {
+    if rules.deltas.is_empty() {
+        return Err(RulesError::NoDeltas);
+    }
+    if rules.deltas.iter().all(|&delta| delta > 0) {
+        return Ok(get_ascending_trailheads(map, rules));
+    }
+    // Trails can go back and forth, so only the reachable goal cells are
+    // counted
+    let trailheads = map
+        .iter_with_positions()
+        .filter(|&(_, &height)| height == rules.start)
+        .map(|(position, _)| {
+            let mut reached = HashSet::from([position]);
+            let mut pending = vec![position];
+            while let Some(pos) = pending.pop() {
+                if map[pos] == rules.goal {
+                    continue;
+                }
+                for next in get_next_steps(pos, map, rules) {
+                    if reached.insert(next) {
+                        pending.push(next);
+                    }
+                }
+            }
+            Trailhead {
+                position,
+                score: reached
+                    .into_iter()
+                    .filter(|&pos| map[pos] == rules.goal)
+                    .count(),
+                rating: None,
+            }
+        })
+        .collect();
+    Ok(trailheads)
+}
+
+// Goes down from the goal to the start, computing for every cell its
+// rating, the sum of the ratings of the cells it can step to, and the set of
+// goal cells it reaches, as a bitset over all of them. Every step goes up,
+// so the cells a height can step to are always done, and the bitsets of the
+// ones that can't be reached anymore are dropped
+fn get_ascending_trailheads(map: &Map<u32>, rules: &TrailRules) -> Vec<Trailhead> {
+    let mut cells: Vec<_> = map
+        .iter_with_positions()
+        .filter(|&(_, &height)| height >= rules.start && height <= rules.goal)
+        .map(|(pos, &height)| (height, pos))
+        .collect();
+    cells.sort_by_key(|&(height, pos)| (std::cmp::Reverse(height), pos));
+    let goals = cells
+        .iter()
+        .filter(|&&(height, _)| height == rules.goal)
+        .count();
+    let words = goals.div_ceil(64);
+    let max_delta = *rules.deltas.iter().max().unwrap() as u32;
+    let index = |pos: Position| pos.0 * map.n() + pos.1;
+    let mut ratings = vec![0; map.m() * map.n()];
+    let mut peaks = vec![Vec::new(); map.m() * map.n()];
+    let mut goal_index = 0;
+    let mut dropped = 0;
+    for current in 0..cells.len() {
+        let (height, pos) = cells[current];
+        let mut bitset = vec![0u64; words];
+        if height == rules.goal {
+            ratings[index(pos)] = 1;
+            bitset[goal_index / 64] |= 1 << (goal_index % 64);
+            goal_index += 1;
+        } else {
+            for next in get_next_steps(pos, map, rules) {
+                ratings[index(pos)] += ratings[index(next)];
+                for (word, next_word) in bitset.iter_mut().zip(&peaks[index(next)]) {
+                    *word |= next_word;
+                }
+            }
+        }
+        peaks[index(pos)] = bitset;
+        while cells[dropped].0 > height + max_delta {
+            peaks[index(cells[dropped].1)] = Vec::new();
+            dropped += 1;
+        }
+    }
+    let mut trailheads: Vec<_> = cells
+        .iter()
+        .filter(|&&(height, _)| height == rules.start)
+        .map(|&(_, position)| Trailhead {
+            position,
+            score: peaks[index(position)]
+                .iter()
+                .map(|word| word.count_ones() as usize)
+                .sum(),
+            rating: Some(ratings[index(position)]),
+        })
+        .collect();
+    trailheads.sort_by_key(|trailhead| trailhead.position);
+    trailheads
+}
+
+// Lazily walks every distinct trail from a trailhead, depth first
+pub struct Trails<'a> {
+    map: &'a Map<u32>,
+    rules: TrailRules,
+    stack: Vec<Vec<Position>>,
+    // Partial trails looked at so far
+    explored: usize,
+}
+
+impl Trails<'_> {
+    // Like `next`, but gives up once `budget` partial trails have been
+    // looked at in total
+    fn next_within(&mut self, budget: usize) -> Result<Option<Vec<Position>>, ()> {
+        while let Some(trail) = self.stack.pop() {
+            if self.explored == budget {
+                return Err(());
+            }
+            self.explored += 1;
+            let last = *trail.last().unwrap();
+            if self.map[last] == self.rules.goal {
+                return Ok(Some(trail));
+            }
+            // Reversed so the trails come out in the order of the directions
+            for next in get_next_steps(last, self.map, &self.rules)
+                .into_iter()
+                .rev()
+            {
+                if trail.contains(&next) {
+                    continue;
+                }
+                let mut longer = trail.clone();
+                longer.push(next);
+                self.stack.push(longer);
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl Iterator for Trails<'_> {
+    type Item = Vec<Position>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_within(usize::MAX).unwrap()
+    }
+}
+
+pub fn get_trails(start: Position, map: &Map<u32>) -> Trails<'_> {
+    get_trails_with_rules(start, map, TrailRules::default())
+}
+
+pub fn get_trails_with_rules(start: Position, map: &Map<u32>, rules: TrailRules) -> Trails<'_> {
+    Trails {
+        map,
+        rules,
+        stack: vec![vec![start]],
+        explored: 0,
+    }
+}
+
+// The rating of a trailhead under any rules, or `None` if more than `budget`
+// partial trails would have to be looked at
+pub fn count_trails(
+    start: Position,
+    map: &Map<u32>,
+    rules: TrailRules,
+    budget: usize,
+) -> Option<usize> {
+    let mut trails = get_trails_with_rules(start, map, rules);
+    let mut count = 0;
+    while trails.next_within(budget).ok()?.is_some() {
+        count += 1;
+    }
+    Some(count)
+}
+
+// At most `limit` trails, without looking for the rest
+pub fn list_trails(start: Position, map: &Map<u32>, limit: usize) -> Vec<Vec<Position>> {
+    get_trails(start, map).take(limit).collect()
+}
+
+// Draws the heights along the trail, with a `.` everywhere else, like the
+// puzzle statement does. Heights above 9 are drawn as letters, and above 35
+// as `#`
+pub fn render_trail(map: &Map<u32>, trail: &[Position]) -> String {
+    let trail: HashSet<_> = trail.iter().copied().collect();
+    let mut output = String::new();
+    for i in 0..map.m() {
+        for j in 0..map.n() {
+            if trail.contains(&(i, j)) {
+                output.push(char::from_digit(map[(i, j)], 36).unwrap_or('#'));
+            } else {
+                output.push('.');
+            }
+        }
+        output.push('\n');
+    }
+    output
+}
+
+pub fn get_reachable_positions(start: Position, map: &Map<u32>) -> HashSet<Position> {
+    let mut reachable = HashSet::new();
+    let mut starters = HashSet::from([start]);
+    for _height in 1..=9 {
+        starters = starters
+            .into_iter()
+            .flat_map(|start| get_reachable_in_one_step(start, map))
+            .collect();
+        reachable.extend(&starters);
+    }
+    reachable
+}
+
+pub fn get_number_of_paths_to_top(start: Position, map: &Map<u32>) -> u32 {
+    let mut starters = vec![start];
+    for _height in 1..=9 {
+        starters = starters
+            .into_iter()
+            .flat_map(|start| get_reachable_in_one_step(start, map))
+            .collect();
+    }
+    starters.len() as u32
+}
+
+fn get_next_steps(pos: Position, map: &Map<u32>, rules: &TrailRules) -> Vec<Position> {
+    let offsets: &[(isize, isize)] = match rules.neighborhood {
+        Neighborhood::Four => &[(-1, 0), (0, 1), (1, 0), (0, -1)],
+        Neighborhood::Eight => &[
+            (-1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+            (1, 0),
+            (1, -1),
+            (0, -1),
+            (-1, -1),
+        ],
+    };
+    let height = map[pos] as i64;
+    offsets
+        .iter()
+        .filter_map(|&(di, dj)| {
+            let i = pos.0.checked_add_signed(di).filter(|&i| i < map.m())?;
+            let j = pos.1.checked_add_signed(dj).filter(|&j| j < map.n())?;
+            Some((i, j))
+        })
+        .filter(|&next| rules.deltas.contains(&(map[next] as i64 - height)))
+        .collect()
+}
+
+fn get_reachable_in_one_step(pos: Position, map: &Map<u32>) -> Vec<Position> {
+    let directions = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+    let starting_height = map[pos];
+    directions
+        .into_iter()
+        .filter_map(move |dir| map.get_neighbor_in_direction(pos, dir))
+        .filter(|&pos| map[pos] == starting_height + 1)
+        .collect()
+}
+
+pub fn read_input(input: &str) -> Result<Map<u32>, InvalidHeight> {
+    read_input_with_encoding(input, HeightEncoding::Digits)
+}
+
+pub fn read_input_with_encoding(
+    input: &str,
+    encoding: HeightEncoding,
+) -> Result<Map<u32>, InvalidHeight> {
+    let inner = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let invalid = |j, found: &str| InvalidHeight {
+                position: (i, j),
+                found: found.to_string(),
+            };
+            match encoding {
+                HeightEncoding::Digits => line
+                    .chars()
+                    .enumerate()
+                    .map(|(j, c)| c.to_digit(10).ok_or_else(|| invalid(j, &c.to_string())))
+                    .collect(),
+                HeightEncoding::Letters => line
+                    .chars()
+                    .enumerate()
+                    .map(|(j, c)| {
+                        (c as u32)
+                            .checked_sub('a' as u32)
+                            .filter(|&height| height < 26)
+                            .ok_or_else(|| invalid(j, &c.to_string()))
+                    })
+                    .collect(),
+                HeightEncoding::Numbers => line
+                    .split_whitespace()
+                    .enumerate()
+                    .map(|(j, number)| number.parse().map_err(|_| invalid(j, number)))
+                    .collect(),
+            }
+        })
+        .collect::<Result<Vec<Vec<_>>, _>>()?;
+    Ok(Map { inner })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1_correct_output_for_test_input() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let result = day10_part1(&contents);
+        assert_eq!(result, 36);
+    }
+
+    #[test]
+    fn trailheads_match_the_per_start_functions() {
+        let contents = fs::read_to_string("input").unwrap();
+        let map = read_input(&contents).unwrap();
+        for trailhead in get_trailheads(&map) {
+            let reachable = get_reachable_positions(trailhead.position, &map);
+            let score = reachable.into_iter().filter(|&pos| map[pos] == 9).count();
+            assert_eq!(trailhead.score, score);
+            let rating = get_number_of_paths_to_top(trailhead.position, &map);
+            assert_eq!(trailhead.rating, Some(rating as usize));
+        }
+    }
+
+    #[test]
+    fn trails_are_listed_lazily_and_rendered() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let map = read_input(&contents).unwrap();
+        assert_eq!(get_trails((0, 2), &map).count(), 20);
+        let trails = list_trails((0, 2), &map, 3);
+        assert_eq!(trails.len(), 3);
+        for trail in &trails {
+            let heights: Vec<_> = trail.iter().map(|&pos| map[pos]).collect();
+            assert_eq!(heights, (0..=9).collect::<Vec<_>>());
+        }
+        let expected = "\
+..01....
+...2....
+...3....
+..549...
+..678...
+........
+........
+........
+";
+        assert_eq!(render_trail(&map, &trails[0]), expected);
+    }
+
+    #[test]
+    fn trail_rules_can_be_changed() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let map = read_input(&contents).unwrap();
+        let trailheads = get_trailheads_with_rules(&map, &TrailRules::default()).unwrap();
+        assert_eq!(trailheads, get_trailheads(&map));
+
+        let map = read_input_with_encoding("abc\nfed\nghi\n", HeightEncoding::Letters).unwrap();
+        let rules = TrailRules {
+            start: 0,
+            goal: 8,
+            ..TrailRules::default()
+        };
+        let trailheads = get_trailheads_with_rules(&map, &rules).unwrap();
+        assert_eq!((trailheads[0].score, trailheads[0].rating), (1, Some(1)));
+        let rules = TrailRules {
+            start: 0,
+            goal: 8,
+            deltas: vec![1, 2],
+            neighborhood: Neighborhood::Eight,
+        };
+        let trailheads = get_trailheads_with_rules(&map, &rules).unwrap();
+        assert_eq!((trailheads[0].score, trailheads[0].rating), (1, Some(9)));
+
+        let map =
+            read_input_with_encoding("10 11 12\n11 12 11\n", HeightEncoding::Numbers).unwrap();
+        let rules = TrailRules {
+            start: 10,
+            goal: 12,
+            deltas: vec![-1, 1],
+            neighborhood: Neighborhood::Four,
+        };
+        let trailheads = get_trailheads_with_rules(&map, &rules).unwrap();
+        assert_eq!(trailheads.len(), 1);
+        assert_eq!((trailheads[0].score, trailheads[0].rating), (2, None));
+        assert_eq!(count_trails((0, 0), &map, rules.clone(), 100), Some(3));
+        assert_eq!(count_trails((0, 0), &map, rules.clone(), 3), None);
+        let trails: Vec<_> = get_trails_with_rules((0, 0), &map, rules).collect();
+        assert!(trails.contains(&vec![(0, 0), (1, 0), (1, 1)]));
+        assert!(trails.contains(&vec![(0, 0), (0, 1), (1, 1)]));
+    }
+
+    #[test]
+    fn trails_going_down_are_not_rated() {
+        let contents = fs::read_to_string("input").unwrap();
+        let map = read_input(&contents).unwrap();
+        let rules = TrailRules {
+            deltas: vec![-1, 1],
+            ..TrailRules::default()
+        };
+        let trailheads = get_trailheads_with_rules(&map, &rules).unwrap();
+        let ascending = get_trailheads(&map);
+        assert_eq!(trailheads.len(), ascending.len());
+        for (trailhead, ascending) in trailheads.iter().zip(&ascending) {
+            assert_eq!(trailhead.rating, None);
+            assert!(trailhead.score >= ascending.score);
+        }
+        let counts: Vec<_> = ascending
+            .iter()
+            .map(|trailhead| count_trails(trailhead.position, &map, rules.clone(), 10000))
+            .collect();
+        assert!(counts.contains(&None));
+        for (count, ascending) in counts.iter().zip(&ascending) {
+            assert!(count.is_none_or(|count| Some(count) >= ascending.rating));
+        }
+    }
+
+    #[test]
+    fn invalid_input_and_rules_are_rejected() {
+        let invalid = |position, found: &str| {
+            Err(InvalidHeight {
+                position,
+                found: found.to_string(),
+            })
+        };
+        assert_eq!(read_input("012\n3x5\n"), invalid((1, 1), "x"));
+        assert_eq!(
+            read_input_with_encoding("abc\ndEf\n", HeightEncoding::Letters),
+            invalid((1, 1), "E")
+        );
+        assert_eq!(
+            read_input_with_encoding("ab\r\n", HeightEncoding::Letters).map(|map| map.n()),
+            Ok(2)
+        );
+        assert_eq!(
+            read_input_with_encoding("ab\rc\n", HeightEncoding::Letters),
+            invalid((0, 2), "\r")
+        );
+        assert_eq!(
+            read_input_with_encoding("1 -2\n", HeightEncoding::Numbers),
+            invalid((0, 1), "-2")
+        );
+
+        let map = read_input("0123\n").unwrap();
+        let rules = TrailRules {
+            deltas: vec![],
+            ..TrailRules::default()
+        };
+        assert_eq!(
+            get_trailheads_with_rules(&map, &rules),
+            Err(RulesError::NoDeltas)
+        );
+    }
+
+    #[test]
+    fn part1_correct_output_for_input() {
+        let contents = fs::read_to_string("input").unwrap();
+        let result = day10_part1(&contents);
+        assert_eq!(result, 574);
+    }
+
+    #[test]
+    fn part2_correct_output_for_test_input() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let result = day10_part2(&contents);
+        assert_eq!(result, 81);
+    }
+
+    #[test]
+    fn part2_correct_output_for_input() {
+        let contents = fs::read_to_string("input").unwrap();
+        let result = day10_part2(&contents);
+        assert_eq!(result, 1238);
+    }
+}
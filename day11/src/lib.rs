@@ -0,0 +1,797 @@
+use std::{
+    collections::{HashMap, HashSet},
+    num::Wrapping,
+};
+
+use bigint::BigUint;
+
+// Stone counts, every operation reports overflow instead of wrapping
+// silently. Wrapping counters never overflow and give counts modulo 2^bits
+pub trait Count: Clone {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_count {
+    ($($t:ty),*) => {
+        $(impl Count for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_add(*self, *other)
+            }
+
+            fn checked_mul(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_mul(*self, *other)
+            }
+        }
+
+        impl Count for Wrapping<$t> {
+            fn zero() -> Self {
+                Wrapping(0)
+            }
+
+            fn one() -> Self {
+                Wrapping(1)
+            }
+
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                Some(self + other)
+            }
+
+            fn checked_mul(&self, other: &Self) -> Option<Self> {
+                Some(self * other)
+            }
+        })*
+    };
+}
+
+impl_count!(u64, u128, usize);
+
+impl Count for BigUint {
+    fn zero() -> Self {
+        BigUint::default()
+    }
+
+    fn one() -> Self {
+        BigUint::from(1)
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CountError {
+    Overflow,
+    // A stone's value doesn't fit in a `Stone`
+    StoneOverflow,
+    // The stones reached more distinct values than the given limit
+    NotClosed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlinkStats {
+    // Both for the stones after the last blink
+    pub distinct_values: usize,
+    pub largest_value: Stone,
+    // The number of stones and of distinct values after each blink,
+    // starting with the initial stones
+    pub growth: Vec<(u128, usize)>,
+}
+
+// Which stones a rule applies to
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Condition {
+    Always,
+    Equals(Stone),
+    // The number of digits in the rules' base is a multiple of this
+    DigitsMultipleOf(u32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Transform {
+    Replace(Stone),
+    Multiply(Stone),
+    // Split the digits into this many equally long stones, leading zeros
+    // are dropped
+    Split(u32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rule {
+    pub condition: Condition,
+    pub transform: Transform,
+}
+
+// The first rule whose condition holds is applied, stones that match no rule
+// stay the same
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BlinkRules {
+    base: Stone,
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RulesError {
+    // Digits need a base of at least 2
+    InvalidBase(Stone),
+    EmptySplit { rule: usize },
+    // The condition doesn't make sure the digits split evenly
+    UnevenSplit { rule: usize },
+}
+
+impl BlinkRules {
+    pub fn new(base: Stone, rules: Vec<Rule>) -> Result<Self, RulesError> {
+        if base < 2 {
+            return Err(RulesError::InvalidBase(base));
+        }
+        for (i, rule) in rules.iter().enumerate() {
+            let Transform::Split(parts) = rule.transform else {
+                continue;
+            };
+            if parts == 0 {
+                return Err(RulesError::EmptySplit { rule: i });
+            }
+            let even = match rule.condition {
+                Condition::Always => parts == 1,
+                Condition::Equals(value) => count_digits(value, base).is_multiple_of(parts),
+                Condition::DigitsMultipleOf(digits) => digits != 0 && digits.is_multiple_of(parts),
+            };
+            if !even {
+                return Err(RulesError::UnevenSplit { rule: i });
+            }
+        }
+        Ok(BlinkRules { base, rules })
+    }
+
+    pub fn base(&self) -> Stone {
+        self.base
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+}
+
+impl Default for BlinkRules {
+    fn default() -> Self {
+        BlinkRules {
+            base: 10,
+            rules: vec![
+                Rule {
+                    condition: Condition::Equals(0),
+                    transform: Transform::Replace(1),
+                },
+                Rule {
+                    condition: Condition::DigitsMultipleOf(2),
+                    transform: Transform::Split(2),
+                },
+                Rule {
+                    condition: Condition::Always,
+                    transform: Transform::Multiply(2024),
+                },
+            ],
+        }
+    }
+}
+
+pub fn day11_part1(input: &str) -> u128 {
+    let stones = read_input(input);
+    apply_blinks_to_stones(stones, 25, &BlinkRules::default()).unwrap()
+}
+
+pub fn day11_part2(input: &str) -> u128 {
+    let stones = read_input(input);
+    apply_blinks_to_stones(stones, 75, &BlinkRules::default()).unwrap()
+}
+
+// How many stones have each value after blinking. Stones with the same
+// value always change the same way, so each value is only transformed once
+// per blink
+pub fn get_stone_histogram(
+    stones: &[Stone],
+    times: usize,
+) -> Result<HashMap<Stone, u128>, CountError> {
+    get_stone_histogram_with_rules(stones, times, &BlinkRules::default())
+}
+
+pub fn get_stone_histogram_with_rules(
+    stones: &[Stone],
+    times: usize,
+    rules: &BlinkRules,
+) -> Result<HashMap<Stone, u128>, CountError> {
+    let mut histogram = get_initial_counts(stones)?;
+    for _ in 0..times {
+        histogram = apply_blink_to_counts(&histogram, rules)?;
+    }
+    Ok(histogram)
+}
+
+// Same as the histogram, but the total can be any width of counter
+pub fn count_stones<C: Count>(
+    stones: &[Stone],
+    times: usize,
+    rules: &BlinkRules,
+) -> Result<C, CountError> {
+    let mut counts: HashMap<Stone, C> = get_initial_counts(stones)?;
+    for _ in 0..times {
+        counts = apply_blink_to_counts(&counts, rules)?;
+    }
+    sum_counts(counts.values())
+}
+
+fn get_initial_counts<C: Count>(stones: &[Stone]) -> Result<HashMap<Stone, C>, CountError> {
+    let mut counts = HashMap::new();
+    for &stone in stones {
+        add_count(&mut counts, stone, &C::one())?;
+    }
+    Ok(counts)
+}
+
+fn apply_blink_to_counts<C: Count>(
+    counts: &HashMap<Stone, C>,
+    rules: &BlinkRules,
+) -> Result<HashMap<Stone, C>, CountError> {
+    let mut next = HashMap::new();
+    for (&stone, count) in counts {
+        for stone in apply_blink_to_stone(stone, rules)? {
+            add_count(&mut next, stone, count)?;
+        }
+    }
+    Ok(next)
+}
+
+fn sum_counts<'a, C: Count + 'a>(counts: impl Iterator<Item = &'a C>) -> Result<C, CountError> {
+    counts
+        .into_iter()
+        .try_fold(C::zero(), |total, count| total.checked_add(count))
+        .ok_or(CountError::Overflow)
+}
+
+fn add_count<C: Count>(
+    counts: &mut HashMap<Stone, C>,
+    stone: Stone,
+    count: &C,
+) -> Result<(), CountError> {
+    let total = counts.entry(stone).or_insert_with(C::zero);
+    *total = total.checked_add(count).ok_or(CountError::Overflow)?;
+    Ok(())
+}
+
+// Every value the stones can ever take, as long as there are at most limit
+// of them
+pub fn find_closed_set(
+    stones: &[Stone],
+    rules: &BlinkRules,
+    limit: usize,
+) -> Result<Vec<Stone>, CountError> {
+    let mut seen: HashSet<Stone> = stones.iter().copied().collect();
+    let mut queue: Vec<Stone> = seen.iter().copied().collect();
+    while let Some(stone) = queue.pop() {
+        if seen.len() > limit {
+            return Err(CountError::NotClosed);
+        }
+        for stone in apply_blink_to_stone(stone, rules)? {
+            if seen.insert(stone) {
+                queue.push(stone);
+            }
+        }
+    }
+    if seen.len() > limit {
+        return Err(CountError::NotClosed);
+    }
+    let mut closed_set: Vec<Stone> = seen.into_iter().collect();
+    closed_set.sort_unstable();
+    Ok(closed_set)
+}
+
+// Raises the transition matrix over the closed set to the number of blinks
+// by squaring, so the cost grows with the log of the blinks but with the
+// cube of the set size. Only worth it for small sets and many blinks
+pub fn count_stones_in_closed_set<C: Count>(
+    stones: &[Stone],
+    times: usize,
+    rules: &BlinkRules,
+    limit: usize,
+) -> Result<C, CountError> {
+    let closed_set = find_closed_set(stones, rules, limit)?;
+    let index: HashMap<Stone, usize> = closed_set
+        .iter()
+        .enumerate()
+        .map(|(i, &stone)| (stone, i))
+        .collect();
+    let n = closed_set.len();
+    // transitions[to][from] is how many stones of value to one stone of value
+    // from becomes after a blink
+    let mut transitions = vec![vec![C::zero(); n]; n];
+    for (from, &stone) in closed_set.iter().enumerate() {
+        for stone in apply_blink_to_stone(stone, rules)? {
+            let to = index[&stone];
+            transitions[to][from] = transitions[to][from]
+                .checked_add(&C::one())
+                .ok_or(CountError::Overflow)?;
+        }
+    }
+    let mut counts = vec![C::zero(); n];
+    for stone in stones {
+        let i = index[stone];
+        counts[i] = counts[i]
+            .checked_add(&C::one())
+            .ok_or(CountError::Overflow)?;
+    }
+    let mut times = times;
+    while times > 0 {
+        if times % 2 == 1 {
+            counts = multiply_vector(&transitions, &counts)?;
+        }
+        times /= 2;
+        if times > 0 {
+            transitions = multiply_matrices(&transitions, &transitions)?;
+        }
+    }
+    sum_counts(counts.iter())
+}
+
+fn multiply_vector<C: Count>(matrix: &[Vec<C>], vector: &[C]) -> Result<Vec<C>, CountError> {
+    matrix
+        .iter()
+        .map(|row| dot_product(row.iter(), vector.iter()))
+        .collect()
+}
+
+fn multiply_matrices<C: Count>(a: &[Vec<C>], b: &[Vec<C>]) -> Result<Vec<Vec<C>>, CountError> {
+    a.iter()
+        .map(|row| {
+            (0..b.len())
+                .map(|j| dot_product(row.iter(), b.iter().map(|column| &column[j])))
+                .collect()
+        })
+        .collect()
+}
+
+fn dot_product<'a, C: Count + 'a>(
+    a: impl Iterator<Item = &'a C>,
+    b: impl Iterator<Item = &'a C>,
+) -> Result<C, CountError> {
+    a.zip(b)
+        .try_fold(C::zero(), |total, (x, y)| {
+            total.checked_add(&x.checked_mul(y)?)
+        })
+        .ok_or(CountError::Overflow)
+}
+
+pub fn get_blink_stats(stones: &[Stone], times: usize) -> Result<BlinkStats, CountError> {
+    get_blink_stats_with_rules(stones, times, &BlinkRules::default())
+}
+
+pub fn get_blink_stats_with_rules(
+    stones: &[Stone],
+    times: usize,
+    rules: &BlinkRules,
+) -> Result<BlinkStats, CountError> {
+    let mut histogram: HashMap<Stone, u128> = get_initial_counts(stones)?;
+    let mut growth = vec![(sum_counts(histogram.values())?, histogram.len())];
+    for _ in 0..times {
+        histogram = apply_blink_to_counts(&histogram, rules)?;
+        growth.push((sum_counts(histogram.values())?, histogram.len()));
+    }
+    Ok(BlinkStats {
+        distinct_values: histogram.len(),
+        largest_value: histogram.keys().copied().max().unwrap_or(0),
+        growth,
+    })
+}
+
+// The memo is keyed by stone and remaining blinks, so it is only valid for
+// the rules it was filled with
+pub fn apply_blinks_to_stones<C: Count>(
+    stones: Vec<Stone>,
+    times: usize,
+    rules: &BlinkRules,
+) -> Result<C, CountError> {
+    let mut memo = HashMap::new();
+    stones.into_iter().try_fold(C::zero(), |total, stone| {
+        let stones = apply_blinks_to_stone(stone, times, rules, &mut memo)?;
+        total.checked_add(&stones).ok_or(CountError::Overflow)
+    })
+}
+
+fn apply_blinks_to_stone<C: Count>(
+    stone: Stone,
+    times: usize,
+    rules: &BlinkRules,
+    memo: &mut HashMap<(Stone, usize), C>,
+) -> Result<C, CountError> {
+    if times == 0 {
+        return Ok(C::one());
+    }
+    if let Some(stones) = memo.get(&(stone, times)) {
+        return Ok(stones.clone());
+    }
+    let mut stones = C::zero();
+    for stone in apply_blink_to_stone(stone, rules)? {
+        let count = apply_blinks_to_stone(stone, times - 1, rules, memo)?;
+        stones = stones.checked_add(&count).ok_or(CountError::Overflow)?;
+    }
+    memo.insert((stone, times), stones.clone());
+    Ok(stones)
+}
+
+pub fn apply_blink_to_stone(stone: Stone, rules: &BlinkRules) -> Result<Vec<Stone>, CountError> {
+    let digits = count_digits(stone, rules.base);
+    let rule = rules.rules.iter().find(|rule| match rule.condition {
+        Condition::Always => true,
+        Condition::Equals(value) => stone == value,
+        Condition::DigitsMultipleOf(parts) => digits.is_multiple_of(parts),
+    });
+    let stones = match rule.map(|rule| &rule.transform) {
+        None => vec![stone],
+        Some(Transform::Replace(value)) => vec![*value],
+        Some(Transform::Multiply(factor)) => {
+            vec![stone
+                .checked_mul(*factor)
+                .ok_or(CountError::StoneOverflow)?]
+        }
+        Some(&Transform::Split(parts)) => split_digits(stone, rules.base, digits, parts),
+    };
+    Ok(stones)
+}
+
+fn count_digits(mut stone: Stone, base: Stone) -> u32 {
+    let mut digits = 1;
+    while stone >= base {
+        stone /= base;
+        digits += 1;
+    }
+    digits
+}
+
+// `BlinkRules::new` makes sure digits is a multiple of parts
+fn split_digits(mut stone: Stone, base: Stone, digits: u32, parts: u32) -> Vec<Stone> {
+    let divisor = base.pow(digits / parts);
+    let mut stones = vec![0; parts as usize];
+    for part in stones.iter_mut().rev() {
+        *part = stone % divisor;
+        stone /= divisor;
+    }
+    stones
+}
+
+pub type Stone = usize;
+
+pub fn read_input(input: &str) -> Vec<Stone> {
+    input
+        .split_whitespace()
+        .map(|c| c.parse().unwrap())
+        .collect::<Vec<Stone>>()
+}
+
+pub mod bigint {
+    use std::{
+        fmt,
+        ops::{Add, Mul},
+    };
+
+    // Unsigned integer of any size, little endian base 2^32 limbs without
+    // trailing zeros
+    #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+    pub struct BigUint {
+        limbs: Vec<u32>,
+    }
+
+    impl BigUint {
+        // The lowest 64 bits, the same as a wrapping u64 count
+        pub fn low_u64(&self) -> u64 {
+            let limb = |i| self.limbs.get(i).copied().unwrap_or(0) as u64;
+            limb(0) | limb(1) << 32
+        }
+
+        fn trim(mut self) -> Self {
+            while self.limbs.last() == Some(&0) {
+                self.limbs.pop();
+            }
+            self
+        }
+
+        // Divides in place and returns the remainder
+        fn div_rem_small(&mut self, divisor: u32) -> u32 {
+            let mut remainder = 0u64;
+            for limb in self.limbs.iter_mut().rev() {
+                let value = remainder << 32 | *limb as u64;
+                *limb = (value / divisor as u64) as u32;
+                remainder = value % divisor as u64;
+            }
+            while self.limbs.last() == Some(&0) {
+                self.limbs.pop();
+            }
+            remainder as u32
+        }
+    }
+
+    impl From<u128> for BigUint {
+        fn from(mut value: u128) -> Self {
+            let mut limbs = Vec::new();
+            while value > 0 {
+                limbs.push(value as u32);
+                value >>= 32;
+            }
+            BigUint { limbs }
+        }
+    }
+
+    impl Add for &BigUint {
+        type Output = BigUint;
+
+        fn add(self, other: &BigUint) -> BigUint {
+            let mut limbs = Vec::with_capacity(self.limbs.len().max(other.limbs.len()) + 1);
+            let mut carry = 0u64;
+            for i in 0..self.limbs.len().max(other.limbs.len()) {
+                let sum = carry
+                    + self.limbs.get(i).copied().unwrap_or(0) as u64
+                    + other.limbs.get(i).copied().unwrap_or(0) as u64;
+                limbs.push(sum as u32);
+                carry = sum >> 32;
+            }
+            limbs.push(carry as u32);
+            BigUint { limbs }.trim()
+        }
+    }
+
+    impl Mul for &BigUint {
+        type Output = BigUint;
+
+        fn mul(self, other: &BigUint) -> BigUint {
+            let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+            for (i, &a) in self.limbs.iter().enumerate() {
+                let mut carry = 0u64;
+                for (j, &b) in other.limbs.iter().enumerate() {
+                    let product = a as u64 * b as u64 + limbs[i + j] as u64 + carry;
+                    limbs[i + j] = product as u32;
+                    carry = product >> 32;
+                }
+                limbs[i + other.limbs.len()] = carry as u32;
+            }
+            BigUint { limbs }.trim()
+        }
+    }
+
+    impl fmt::Display for BigUint {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            // Chunks of nine decimal digits, lowest first
+            let mut value = self.clone();
+            let mut chunks = Vec::new();
+            while !value.limbs.is_empty() {
+                chunks.push(value.div_rem_small(1_000_000_000));
+            }
+            match chunks.split_last() {
+                None => write!(f, "0"),
+                Some((first, rest)) => {
+                    write!(f, "{first}")?;
+                    for chunk in rest.iter().rev() {
+                        write!(f, "{chunk:09}")?;
+                    }
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn arithmetic_and_display() {
+            let max = BigUint::from(u128::MAX);
+            assert_eq!(
+                (&max + &BigUint::from(1)).to_string(),
+                "340282366920938463463374607431768211456"
+            );
+            let big = BigUint::from(10u128.pow(20));
+            assert_eq!((&big * &big).to_string(), format!("1{}", "0".repeat(40)));
+            assert_eq!(BigUint::default().to_string(), "0");
+            assert_eq!(BigUint::from(1000000007).to_string(), "1000000007");
+            assert_eq!((&max * &BigUint::default()), BigUint::default());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1_correct_output_for_test_input() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let result = day11_part1(&contents);
+        assert_eq!(result, 55312);
+    }
+
+    #[test]
+    fn histogram_after_blinks() {
+        let histogram = get_stone_histogram(&[125, 17], 6).unwrap();
+        let mut expected = HashMap::new();
+        for stone in [
+            2097446912, 14168, 4048, 2, 0, 2, 4, 40, 48, 2024, 40, 48, 80, 96, 2, 8, 6, 7, 6, 0, 3,
+            2,
+        ] {
+            *expected.entry(stone).or_insert(0) += 1;
+        }
+        assert_eq!(histogram, expected);
+        let histogram = get_stone_histogram(&[125, 17], 75).unwrap();
+        assert_eq!(histogram.values().sum::<u128>(), 65601038650482);
+    }
+
+    #[test]
+    fn custom_rules() {
+        let rules = BlinkRules::new(
+            10,
+            vec![
+                Rule {
+                    condition: Condition::DigitsMultipleOf(3),
+                    transform: Transform::Split(3),
+                },
+                Rule {
+                    condition: Condition::Equals(7),
+                    transform: Transform::Replace(0),
+                },
+                Rule {
+                    condition: Condition::Always,
+                    transform: Transform::Multiply(3),
+                },
+            ],
+        )
+        .unwrap();
+        assert_eq!(apply_blink_to_stone(120456, &rules), Ok(vec![12, 4, 56]));
+        assert_eq!(apply_blink_to_stone(7, &rules), Ok(vec![0]));
+        assert_eq!(apply_blink_to_stone(0, &rules), Ok(vec![0]));
+        assert_eq!(apply_blink_to_stone(45, &rules), Ok(vec![135]));
+        let binary = BlinkRules::new(2, BlinkRules::default().rules().to_vec()).unwrap();
+        assert_eq!(apply_blink_to_stone(0b1011, &binary), Ok(vec![0b10, 0b11]));
+        assert_eq!(apply_blink_to_stone(0b101, &binary), Ok(vec![0b101 * 2024]));
+        assert_eq!(
+            apply_blink_to_stone(1, &BlinkRules::new(10, vec![]).unwrap()),
+            Ok(vec![1])
+        );
+
+        let histogram = get_stone_histogram_with_rules(&[125, 17], 30, &rules).unwrap();
+        let stones: u128 = apply_blinks_to_stones(vec![125, 17], 30, &rules).unwrap();
+        assert_eq!(histogram.values().sum::<u128>(), stones);
+    }
+
+    #[test]
+    fn checked_and_wide_counts() {
+        let rules = BlinkRules::default();
+        assert_eq!(count_stones(&[125, 17], 75, &rules), Ok(65601038650482u64));
+        assert_eq!(
+            count_stones::<u64>(&[125, 17], 200, &rules),
+            Err(CountError::Overflow)
+        );
+        assert_eq!(
+            apply_blinks_to_stones::<u128>(vec![125, 17], 300, &rules),
+            Err(CountError::Overflow)
+        );
+        let wide: BigUint = count_stones(&[125, 17], 300, &rules).unwrap();
+        let memoised: BigUint = apply_blinks_to_stones(vec![125, 17], 300, &rules).unwrap();
+        let Wrapping(wrapped) = count_stones::<Wrapping<u64>>(&[125, 17], 300, &rules).unwrap();
+        assert_eq!(wide, memoised);
+        assert_eq!(wide.to_string().len(), 55);
+        assert_eq!(wide.low_u64(), wrapped);
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected() {
+        let split = |condition, parts| {
+            vec![Rule {
+                condition,
+                transform: Transform::Split(parts),
+            }]
+        };
+        assert_eq!(BlinkRules::new(1, vec![]), Err(RulesError::InvalidBase(1)));
+        assert_eq!(BlinkRules::new(0, vec![]), Err(RulesError::InvalidBase(0)));
+        assert_eq!(
+            BlinkRules::new(10, split(Condition::DigitsMultipleOf(2), 0)),
+            Err(RulesError::EmptySplit { rule: 0 })
+        );
+        assert_eq!(
+            BlinkRules::new(10, split(Condition::DigitsMultipleOf(2), 3)),
+            Err(RulesError::UnevenSplit { rule: 0 })
+        );
+        assert_eq!(
+            BlinkRules::new(10, split(Condition::Always, 2)),
+            Err(RulesError::UnevenSplit { rule: 0 })
+        );
+        assert_eq!(
+            BlinkRules::new(10, split(Condition::Equals(123), 2)),
+            Err(RulesError::UnevenSplit { rule: 0 })
+        );
+        assert!(BlinkRules::new(10, split(Condition::Equals(1234), 2)).is_ok());
+        assert!(BlinkRules::new(10, split(Condition::DigitsMultipleOf(6), 3)).is_ok());
+    }
+
+    #[test]
+    fn overflowing_stones_are_reported() {
+        let rules = BlinkRules::new(
+            10,
+            vec![Rule {
+                condition: Condition::Always,
+                transform: Transform::Multiply(2024),
+            }],
+        )
+        .unwrap();
+        let error = CountError::StoneOverflow;
+        assert_eq!(count_stones::<u128>(&[1], 10, &rules), Err(error));
+        assert_eq!(
+            apply_blinks_to_stones::<u128>(vec![1], 10, &rules),
+            Err(error)
+        );
+        assert_eq!(find_closed_set(&[1], &rules, 1000), Err(error));
+        assert_eq!(get_blink_stats_with_rules(&[1], 10, &rules), Err(error));
+    }
+
+    #[test]
+    fn closed_set_counts() {
+        let rules = BlinkRules::default();
+        assert_eq!(find_closed_set(&[0], &rules, 1000).unwrap().len(), 54);
+        assert_eq!(
+            find_closed_set(&[0], &rules, 10),
+            Err(CountError::NotClosed)
+        );
+        let with_matrix: BigUint =
+            count_stones_in_closed_set(&[125, 17], 300, &rules, 1000).unwrap();
+        let iterated: BigUint = count_stones(&[125, 17], 300, &rules).unwrap();
+        assert_eq!(with_matrix, iterated);
+        let with_matrix: Wrapping<u64> =
+            count_stones_in_closed_set(&[0], 20000, &rules, 1000).unwrap();
+        let iterated: Wrapping<u64> = count_stones(&[0], 20000, &rules).unwrap();
+        assert_eq!(with_matrix, iterated);
+        assert!(count_stones_in_closed_set::<Wrapping<u64>>(&[0], 1000000, &rules, 1000).is_ok());
+    }
+
+    #[test]
+    fn blink_stats() {
+        let stats = get_blink_stats(&[125, 17], 6).unwrap();
+        assert_eq!(stats.distinct_values, 15);
+        assert_eq!(stats.largest_value, 2097446912);
+        assert_eq!(
+            stats.growth,
+            [(2, 2), (3, 3), (4, 4), (5, 5), (9, 8), (13, 12), (22, 15)]
+        );
+    }
+
+    #[test]
+    fn part1_correct_output_for_input() {
+        let contents = fs::read_to_string("input").unwrap();
+        let result = day11_part1(&contents);
+        assert_eq!(result, 189092);
+    }
+
+    #[test]
+    fn part2_correct_output_for_test_input() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let result = day11_part2(&contents);
+        assert_eq!(result, 65601038650482);
+    }
+
+    #[test]
+    fn part2_correct_output_for_input() {
+        let contents = fs::read_to_string("input").unwrap();
+        let result = day11_part2(&contents);
+        assert_eq!(result, 224869647102559);
+    }
+}
@@ -0,0 +1,709 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    ops::{Index, IndexMut},
+};
+
+pub type Position = (usize, usize);
+
+pub type RegionId = usize;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix<T> {
+    inner: Vec<Vec<T>>,
+}
+
+impl<T> Matrix<T> {
+    fn get_neighbor_in_direction(&self, pos: Position, direction: Direction) -> Option<Position> {
+        let change = match direction {
+            Direction::Up => (-1, 0),
+            Direction::Left => (0, -1),
+            Direction::Down => (1, 0),
+            Direction::Right => (0, 1),
+        };
+        if pos.0 == 0 && change.0 == -1
+            || pos.1 == 0 && change.1 == -1
+            || pos.0 == self.m() - 1 && change.0 == 1
+            || pos.1 == self.n() - 1 && change.1 == 1
+        {
+            return None;
+        }
+        Some((
+            (pos.0 as isize + change.0) as usize,
+            (pos.1 as isize + change.1) as usize,
+        ))
+    }
+
+    fn get_neighbors(&self, pos: Position) -> Vec<Position> {
+        let directions = [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ];
+        directions
+            .into_iter()
+            .filter_map(move |dir| self.get_neighbor_in_direction(pos, dir))
+            .collect()
+    }
+
+    fn get_with_offset(&self, pos: Position, offset: (isize, isize)) -> Option<&T> {
+        let i = pos.0.checked_add_signed(offset.0)?;
+        let j = pos.1.checked_add_signed(offset.1)?;
+        self.inner.get(i)?.get(j)
+    }
+
+    fn positions(&self) -> impl Iterator<Item = Position> {
+        let n = self.n();
+        (0..self.m()).flat_map(move |i| (0..n).map(move |j| (i, j)))
+    }
+
+    pub fn m(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn n(&self) -> usize {
+        self.inner[0].len()
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Matrix<T> {
+    fn from(inner: Vec<Vec<T>>) -> Self {
+        Matrix { inner }
+    }
+}
+
+impl<T> Index<Position> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        &self.inner[pos.0][pos.1]
+    }
+}
+
+impl<T> IndexMut<Position> for Matrix<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        &mut self.inner[pos.0][pos.1]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub id: RegionId,
+    // First position of the region in reading order
+    pub origin: Position,
+    pub area: usize,
+    pub perimeter: usize,
+    pub sides: usize,
+    // Top left and bottom right positions, both inside the box
+    pub bounding_box: (Position, Position),
+}
+
+#[derive(Debug, Clone)]
+pub struct Regions {
+    pub labels: Matrix<RegionId>,
+    pub regions: Vec<Region>,
+    pub neighborhood: Neighborhood,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Neighborhood {
+    #[default]
+    Four,
+    // Diagonal neighbors also join regions
+    Eight,
+}
+
+#[derive(Debug, Clone)]
+pub struct RegionOptions<T> {
+    pub neighborhood: Neighborhood,
+    // Values in the same group join regions as if they were equal
+    pub equivalent: Vec<Vec<T>>,
+}
+
+impl<T> Default for RegionOptions<T> {
+    fn default() -> Self {
+        RegionOptions {
+            neighborhood: Neighborhood::Four,
+            equivalent: Vec::new(),
+        }
+    }
+}
+
+impl<T: Eq> RegionOptions<T> {
+    fn are_equivalent(&self, a: &T, b: &T) -> bool {
+        a == b
+            || self
+                .equivalent
+                .iter()
+                .any(|group| group.contains(a) && group.contains(b))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Enclosures {
+    // The innermost region whose hole each region is in
+    pub enclosed_by: Vec<Option<RegionId>>,
+    // The fences of each region without the ones around its holes
+    pub outer_perimeter: Vec<usize>,
+    pub outer_sides: Vec<usize>,
+}
+
+impl Enclosures {
+    // Pairs of inner region and the region enclosing it
+    pub fn inner_regions(&self) -> impl Iterator<Item = (RegionId, RegionId)> + '_ {
+        self.enclosed_by
+            .iter()
+            .enumerate()
+            .filter_map(|(id, enclosing)| enclosing.map(|enclosing| (id, enclosing)))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fence {
+    Perimeter,
+    Sides,
+}
+
+struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(len: usize) -> Self {
+        UnionFind {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+        }
+    }
+
+    fn find(&mut self, mut x: usize) -> usize {
+        while self.parents[x] != x {
+            self.parents[x] = self.parents[self.parents[x]];
+            x = self.parents[x];
+        }
+        x
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        if self.sizes[a] < self.sizes[b] {
+            (a, b) = (b, a);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+    }
+}
+
+pub fn day12_part1(input: &str) -> usize {
+    let matrix = read_input(input);
+    get_fence_price(&label_regions(&matrix), Fence::Perimeter, false)
+}
+
+pub fn day12_part2(input: &str) -> usize {
+    let matrix = read_input(input);
+    get_fence_price(&label_regions(&matrix), Fence::Sides, false)
+}
+
+// With inner regions, the fence between a region and the regions in its
+// holes is only paid for by the inner ones
+pub fn get_fence_price(regions: &Regions, fence: Fence, inner_regions: bool) -> usize {
+    let enclosures = inner_regions.then(|| get_enclosures(regions));
+    regions
+        .regions
+        .iter()
+        .map(|region| {
+            let length = match (fence, &enclosures) {
+                (Fence::Perimeter, None) => region.perimeter,
+                (Fence::Sides, None) => region.sides,
+                (Fence::Perimeter, Some(enclosures)) => enclosures.outer_perimeter[region.id],
+                (Fence::Sides, Some(enclosures)) => enclosures.outer_sides[region.id],
+            };
+            length * region.area
+        })
+        .sum()
+}
+
+pub fn label_regions<T: Eq>(matrix: &Matrix<T>) -> Regions {
+    label_regions_with_options(matrix, &RegionOptions::default())
+}
+
+// Joins every position with its equivalent neighbors above and to the left
+// in one pass, then numbers the regions in reading order of their first
+// position
+pub fn label_regions_with_options<T: Eq>(
+    matrix: &Matrix<T>,
+    options: &RegionOptions<T>,
+) -> Regions {
+    let offsets: &[(isize, isize)] = match options.neighborhood {
+        Neighborhood::Four => &[(-1, 0), (0, -1)],
+        Neighborhood::Eight => &[(-1, 0), (0, -1), (-1, -1), (-1, 1)],
+    };
+    let n = matrix.n();
+    let mut sets = UnionFind::new(matrix.m() * n);
+    for pos in matrix.positions() {
+        for &(di, dj) in offsets {
+            if let Some(value) = matrix.get_with_offset(pos, (di, dj)) {
+                if options.are_equivalent(value, &matrix[pos]) {
+                    let neighbor =
+                        (pos.0 as isize + di) as usize * n + (pos.1 as isize + dj) as usize;
+                    sets.union(pos.0 * n + pos.1, neighbor);
+                }
+            }
+        }
+    }
+
+    let mut ids = vec![None; matrix.m() * n];
+    let mut labels = Matrix::from(vec![vec![0; n]; matrix.m()]);
+    let mut regions: Vec<Region> = Vec::new();
+    for pos in matrix.positions() {
+        let root = sets.find(pos.0 * n + pos.1);
+        let id = *ids[root].get_or_insert_with(|| {
+            regions.push(Region {
+                id: regions.len(),
+                origin: pos,
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+                bounding_box: (pos, pos),
+            });
+            regions.len() - 1
+        });
+        labels[pos] = id;
+        let region = &mut regions[id];
+        region.area += 1;
+        let (top_left, bottom_right) = &mut region.bounding_box;
+        top_left.1 = top_left.1.min(pos.1);
+        bottom_right.0 = bottom_right.0.max(pos.0);
+        bottom_right.1 = bottom_right.1.max(pos.1);
+    }
+
+    // Every corner of the outline is where a side ends
+    for pos in matrix.positions() {
+        let id = labels[pos];
+        let same_neighbors = labels
+            .get_neighbors(pos)
+            .into_iter()
+            .filter(|&neighbor| labels[neighbor] == id)
+            .count();
+        regions[id].perimeter += 4 - same_neighbors;
+        regions[id].sides += count_corners(pos, &labels);
+    }
+    Regions {
+        labels,
+        regions,
+        neighborhood: options.neighborhood,
+    }
+}
+
+// Fills the outside of each region's bounding box through the positions not
+// in the region. Whatever the fill can't reach is in one of its holes
+pub fn get_enclosures(regions: &Regions) -> Enclosures {
+    let labels = &regions.labels;
+    let mut enclosures = Enclosures {
+        enclosed_by: vec![None; regions.regions.len()],
+        outer_perimeter: vec![0; regions.regions.len()],
+        outer_sides: vec![0; regions.regions.len()],
+    };
+    let box_area = |id: RegionId| {
+        let ((top, left), (bottom, right)) = regions.regions[id].bounding_box;
+        (bottom - top + 1) * (right - left + 1)
+    };
+    for region in &regions.regions {
+        let ((top, left), (bottom, right)) = region.bounding_box;
+        let in_box = |(i, j): Position| (top..=bottom).contains(&i) && (left..=right).contains(&j);
+        let mut outside = vec![vec![false; right - left + 1]; bottom - top + 1];
+        let mut to_fill: Vec<Position> = (top..=bottom)
+            .flat_map(|i| (left..=right).map(move |j| (i, j)))
+            .filter(|&(i, j)| i == top || i == bottom || j == left || j == right)
+            .filter(|&pos| labels[pos] != region.id)
+            .collect();
+        for &(i, j) in &to_fill {
+            outside[i - top][j - left] = true;
+        }
+        while let Some(pos) = to_fill.pop() {
+            for neighbor in labels.get_neighbors(pos) {
+                let (i, j) = neighbor;
+                if in_box(neighbor) && labels[neighbor] != region.id && !outside[i - top][j - left]
+                {
+                    outside[i - top][j - left] = true;
+                    to_fill.push(neighbor);
+                }
+            }
+        }
+
+        for i in top..=bottom {
+            for j in left..=right {
+                let id = labels[(i, j)];
+                if id == region.id {
+                    let (outer_perimeter, outer_sides) =
+                        count_outer_fences((i, j), labels, &outside, (top, left));
+                    enclosures.outer_perimeter[region.id] += outer_perimeter;
+                    enclosures.outer_sides[region.id] += outer_sides;
+                } else if !outside[i - top][j - left]
+                    && enclosures.enclosed_by[id]
+                        .is_none_or(|enclosing| box_area(region.id) < box_area(enclosing))
+                {
+                    enclosures.enclosed_by[id] = Some(region.id);
+                }
+            }
+        }
+    }
+    enclosures
+}
+
+// The fence edges and corners of a position that face the outside of its
+// region rather than a hole
+fn count_outer_fences(
+    pos: Position,
+    labels: &Matrix<RegionId>,
+    outside: &[Vec<bool>],
+    (top, left): Position,
+) -> (usize, usize) {
+    let id = labels[pos];
+    // None when inside the region, otherwise whether it is outside
+    let classify = |(di, dj): (isize, isize)| {
+        let i = pos.0 as isize + di - top as isize;
+        let j = pos.1 as isize + dj - left as isize;
+        if labels.get_with_offset(pos, (di, dj)) == Some(&id) {
+            None
+        } else if i < 0 || j < 0 {
+            Some(true)
+        } else {
+            let row = outside.get(i as usize);
+            Some(
+                row.and_then(|row| row.get(j as usize))
+                    .is_none_or(|&outside| outside),
+            )
+        }
+    };
+    let perimeter = [(-1, 0), (0, 1), (1, 0), (0, -1)]
+        .into_iter()
+        .filter(|&offset| classify(offset) == Some(true))
+        .count();
+    let corners = [(-1, -1), (-1, 1), (1, 1), (1, -1)]
+        .into_iter()
+        .filter(|&(di, dj)| match (classify((di, 0)), classify((0, dj))) {
+            (Some(vertical), Some(horizontal)) => vertical || horizontal,
+            (None, None) => classify((di, dj)) == Some(true),
+            _ => false,
+        })
+        .count();
+    (perimeter, corners)
+}
+
+// Looks at the four corners of the position. A corner is convex when both
+// orthogonal neighbors are outside the region, and concave when both are
+// inside but the diagonal one is not
+fn count_corners(pos: Position, labels: &Matrix<RegionId>) -> usize {
+    let id = labels[pos];
+    let inside = |offset| labels.get_with_offset(pos, offset) == Some(&id);
+    [(-1, -1), (-1, 1), (1, 1), (1, -1)]
+        .into_iter()
+        .filter(|&(di, dj)| {
+            let vertical = inside((di, 0));
+            let horizontal = inside((0, dj));
+            !vertical && !horizontal || vertical && horizontal && !inside((di, dj))
+        })
+        .count()
+}
+
+// The outline of the region as closed polygons through the corners of the
+// positions, with (row, column) points. The first polygon is the outer one
+// and goes clockwise, the rest are holes and go counter-clockwise
+pub fn get_outlines(regions: &Regions, id: RegionId) -> Vec<Vec<Position>> {
+    let labels = &regions.labels;
+    let region = &regions.regions[id];
+    // Fence edges with the region on their right, by starting point
+    let mut edges: BTreeMap<Position, Vec<Position>> = BTreeMap::new();
+    let ((top, left), (bottom, right)) = region.bounding_box;
+    for i in top..=bottom {
+        for j in left..=right {
+            if labels[(i, j)] != region.id {
+                continue;
+            }
+            let inside = |offset| labels.get_with_offset((i, j), offset) == Some(&region.id);
+            let fences = [
+                ((-1, 0), (i, j), (i, j + 1)),
+                ((0, 1), (i, j + 1), (i + 1, j + 1)),
+                ((1, 0), (i + 1, j + 1), (i + 1, j)),
+                ((0, -1), (i + 1, j), (i, j)),
+            ];
+            for (offset, start, end) in fences {
+                if !inside(offset) {
+                    edges.entry(start).or_default().push(end);
+                }
+            }
+        }
+    }
+
+    let mut outlines = Vec::new();
+    while let Some((&start, _)) = edges.first_key_value() {
+        let mut vertices = vec![start];
+        let mut direction = None;
+        let mut current = start;
+        loop {
+            let ends = edges.get_mut(&current).unwrap();
+            // Where the region touches itself at a point, keep to the
+            // current position by turning right, or go around the diagonal
+            // one too by turning left when they are neighbors
+            let turn = match regions.neighborhood {
+                Neighborhood::Four => turn_right,
+                Neighborhood::Eight => turn_left,
+            };
+            let next = match direction {
+                Some(direction) if ends.len() > 1 => ends
+                    .iter()
+                    .position(|&end| get_direction(current, end) == turn(direction))
+                    .unwrap_or(0),
+                _ => 0,
+            };
+            let end = ends.swap_remove(next);
+            if ends.is_empty() {
+                edges.remove(&current);
+            }
+            direction = Some(get_direction(current, end));
+            current = end;
+            if current == start {
+                break;
+            }
+            vertices.push(current);
+        }
+        // Drop the points in the middle of straight lines
+        let k = vertices.len();
+        let corners = (0..k)
+            .filter(|&v| {
+                let previous = vertices[(v + k - 1) % k];
+                let next = vertices[(v + 1) % k];
+                get_direction(previous, vertices[v]) != get_direction(vertices[v], next)
+            })
+            .map(|v| vertices[v])
+            .collect();
+        outlines.push(corners);
+    }
+    outlines
+}
+
+fn get_direction(from: Position, to: Position) -> (isize, isize) {
+    (
+        to.0 as isize - from.0 as isize,
+        to.1 as isize - from.1 as isize,
+    )
+}
+
+fn turn_right((di, dj): (isize, isize)) -> (isize, isize) {
+    (dj, -di)
+}
+
+fn turn_left((di, dj): (isize, isize)) -> (isize, isize) {
+    (-dj, di)
+}
+
+// One path per region, holes are left unfilled by the even-odd rule
+pub fn render_svg(regions: &Regions) -> String {
+    let labels = &regions.labels;
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {} {}\">\n",
+        labels.n(),
+        labels.m()
+    );
+    for region in &regions.regions {
+        let path = get_outlines(regions, region.id)
+            .iter()
+            .map(|polygon| {
+                let points = polygon
+                    .iter()
+                    .map(|&(i, j)| format!("{j} {i}"))
+                    .collect::<Vec<_>>();
+                format!("M {} Z", points.join(" L "))
+            })
+            .collect::<Vec<_>>();
+        writeln!(
+            svg,
+            "  <path d=\"{}\" fill=\"hsl({}, 70%, 60%)\" fill-rule=\"evenodd\" stroke=\"black\" stroke-width=\"0.05\"/>",
+            path.join(" "),
+            region.id * 137 % 360
+        )
+        .unwrap();
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+pub fn read_input(input: &str) -> Matrix<char> {
+    Matrix {
+        inner: input
+            .lines()
+            .map(|line| line.chars().collect())
+            .collect::<Vec<Vec<_>>>(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1_correct_output_for_test_input() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let result = day12_part1(&contents);
+        assert_eq!(result, 1930);
+    }
+
+    #[test]
+    fn label_regions_of_any_type() {
+        let matrix = Matrix::from(vec![
+            vec![1, 1, 1, 1],
+            vec![2, 2, 3, 4],
+            vec![2, 2, 3, 3],
+            vec![5, 5, 5, 3],
+        ]);
+        let Regions {
+            labels, regions, ..
+        } = label_regions(&matrix);
+        assert_eq!(
+            labels,
+            Matrix::from(vec![
+                vec![0, 0, 0, 0],
+                vec![1, 1, 2, 3],
+                vec![1, 1, 2, 2],
+                vec![4, 4, 4, 2],
+            ])
+        );
+        let stats: Vec<_> = regions
+            .iter()
+            .map(|region| (region.area, region.perimeter, region.sides))
+            .collect();
+        assert_eq!(
+            stats,
+            [(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+        );
+        assert_eq!(regions[2].origin, (1, 2));
+        assert_eq!(regions[2].bounding_box, ((1, 2), (3, 3)));
+        assert_eq!(regions[4].bounding_box, ((3, 0), (3, 2)));
+    }
+
+    #[test]
+    fn outlines_with_holes() {
+        let matrix = read_input("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n");
+        let regions = label_regions(&matrix);
+        let outlines = get_outlines(&regions, 0);
+        assert_eq!(outlines.len(), 5);
+        assert_eq!(outlines[0], [(0, 0), (0, 5), (5, 5), (5, 0)]);
+        assert_eq!(outlines[1], [(1, 1), (2, 1), (2, 2), (1, 2)]);
+        assert_eq!(regions.regions[0].sides, 20);
+
+        let contents = fs::read_to_string("test_input").unwrap();
+        let regions = label_regions(&read_input(&contents));
+        for region in &regions.regions {
+            let outlines = get_outlines(&regions, region.id);
+            let corners: usize = outlines.iter().map(|polygon| polygon.len()).sum();
+            assert_eq!(corners, region.sides);
+        }
+    }
+
+    #[test]
+    fn svg_outline() {
+        let regions = label_regions(&read_input("A\n"));
+        assert_eq!(
+            render_svg(&regions),
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 1 1\">\n  \
+             <path d=\"M 0 0 L 1 0 L 1 1 L 0 1 Z\" fill=\"hsl(0, 70%, 60%)\" \
+             fill-rule=\"evenodd\" stroke=\"black\" stroke-width=\"0.05\"/>\n</svg>\n"
+        );
+    }
+
+    #[test]
+    fn diagonal_and_equivalent_regions() {
+        let matrix = read_input("AB\nBA\n");
+        assert_eq!(label_regions(&matrix).regions.len(), 4);
+        let options = RegionOptions {
+            neighborhood: Neighborhood::Eight,
+            ..RegionOptions::default()
+        };
+        let regions = label_regions_with_options(&matrix, &options);
+        assert_eq!(regions.labels, Matrix::from(vec![vec![0, 1], vec![1, 0]]));
+        assert_eq!(regions.regions[0].perimeter, 8);
+        assert_eq!(regions.regions[0].sides, 8);
+        assert_eq!(
+            get_outlines(&regions, 0),
+            [vec![
+                (0, 0),
+                (0, 1),
+                (1, 1),
+                (1, 2),
+                (2, 2),
+                (2, 1),
+                (1, 1),
+                (1, 0)
+            ]]
+        );
+
+        let matrix = read_input("AaB\nBBB\n");
+        let options = RegionOptions {
+            neighborhood: Neighborhood::Four,
+            equivalent: vec![vec!['A', 'a']],
+        };
+        let regions = label_regions_with_options(&matrix, &options);
+        assert_eq!(
+            regions.labels,
+            Matrix::from(vec![vec![0, 0, 1], vec![1, 1, 1]])
+        );
+    }
+
+    #[test]
+    fn inner_regions() {
+        let regions = label_regions(&read_input("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n"));
+        let enclosures = get_enclosures(&regions);
+        assert_eq!(
+            enclosures.inner_regions().collect::<Vec<_>>(),
+            [(1, 0), (2, 0), (3, 0), (4, 0)]
+        );
+        assert_eq!(enclosures.outer_perimeter[0], 20);
+        assert_eq!(enclosures.outer_sides[0], 4);
+        assert_eq!(get_fence_price(&regions, Fence::Perimeter, false), 772);
+        assert_eq!(get_fence_price(&regions, Fence::Perimeter, true), 436);
+        assert_eq!(get_fence_price(&regions, Fence::Sides, false), 436);
+        assert_eq!(get_fence_price(&regions, Fence::Sides, true), 100);
+
+        let regions = label_regions(&read_input("AAAAA\nABBBA\nABCBA\nABBBA\nAAAAA\n"));
+        let enclosures = get_enclosures(&regions);
+        assert_eq!(enclosures.enclosed_by, [None, Some(0), Some(1)]);
+        assert_eq!(enclosures.outer_perimeter, [20, 12, 4]);
+        assert_eq!(enclosures.outer_sides, [4, 4, 4]);
+    }
+
+    #[test]
+    fn part1_correct_output_for_input() {
+        let contents = fs::read_to_string("input").unwrap();
+        let result = day12_part1(&contents);
+        assert_eq!(result, 1371306);
+    }
+
+    #[test]
+    fn part2_correct_output_for_test_input() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let result = day12_part2(&contents);
+        assert_eq!(result, 1206);
+    }
+
+    #[test]
+    fn part2_correct_output_for_input() {
+        let contents = fs::read_to_string("input").unwrap();
+        let result = day12_part2(&contents);
+        assert_eq!(result, 805880);
+    }
+}
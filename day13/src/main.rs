@@ -18,10 +18,7 @@ fn main() {
 
 fn day13_part1(input: &str) -> u64 {
     let (_, claw_machines) = read_input(input).unwrap();
-    claw_machines
-        .into_iter()
-        .filter_map(solve_claw_machine)
-        .sum()
+    get_total_tokens(claw_machines).expect("token count overflowed")
 }
 
 fn day13_part2(input: &str) -> u64 {
@@ -29,8 +26,9 @@ fn day13_part2(input: &str) -> u64 {
     claw_machines
         .into_iter()
         .map(adjust_prize)
-        .filter_map(solve_claw_machine)
-        .sum()
+        .collect::<Result<Vec<_>, _>>()
+        .and_then(get_total_tokens)
+        .expect("token count overflowed")
 }
 
 #[derive(Debug)]
@@ -40,31 +38,140 @@ struct ClawMachine {
     prize: Vector,
 }
 
-fn solve_claw_machine(machine: ClawMachine) -> Option<u64> {
-    let denominator_determinant = ((machine.a_movement.0 * machine.b_movement.1) as i64
-        - (machine.b_movement.0 * machine.a_movement.1) as i64)
-        as f64;
-    let x_determinant = ((machine.prize.0 * machine.b_movement.1) as i64
-        - (machine.b_movement.0 * machine.prize.1) as i64) as f64;
-    let y_determinant = ((machine.a_movement.0 * machine.prize.1) as i64
-        - (machine.prize.0 * machine.a_movement.1) as i64) as f64;
-    if denominator_determinant == 0.0 {
-        return None;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Overflow;
+
+fn get_total_tokens(claw_machines: Vec<ClawMachine>) -> Result<u64, Overflow> {
+    claw_machines.into_iter().try_fold(0u64, |total, machine| {
+        let tokens = solve_claw_machine(machine)?.unwrap_or(0);
+        total.checked_add(tokens).ok_or(Overflow)
+    })
+}
+
+// Cramer's rule, the presses only exist if both determinants are exact
+// multiples of the denominator and not negative
+fn solve_claw_machine(machine: ClawMachine) -> Result<Option<u64>, Overflow> {
+    let a_movement = widen(machine.a_movement);
+    let b_movement = widen(machine.b_movement);
+    let prize = widen(machine.prize);
+    let denominator_determinant = determinant(a_movement, b_movement)?;
+    if denominator_determinant == 0 {
+        return solve_parallel_claw_machine(a_movement, b_movement, prize);
+    }
+    let x_determinant = determinant(prize, b_movement)?;
+    let y_determinant = determinant(a_movement, prize)?;
+
+    let divide = |determinant: i128| {
+        let remainder = determinant
+            .checked_rem(denominator_determinant)
+            .ok_or(Overflow)?;
+        let quotient = determinant
+            .checked_div(denominator_determinant)
+            .ok_or(Overflow)?;
+        Ok((remainder == 0 && quotient >= 0).then_some(quotient))
+    };
+    let (Some(a), Some(b)) = (divide(x_determinant)?, divide(y_determinant)?) else {
+        return Ok(None);
+    };
+
+    count_tokens(a, b).map(Some)
+}
+
+// When both buttons move along the same line, the prize has to be on it too,
+// and then one coordinate decides the presses
+fn solve_parallel_claw_machine(
+    a_movement: (i128, i128),
+    b_movement: (i128, i128),
+    prize: (i128, i128),
+) -> Result<Option<u64>, Overflow> {
+    if determinant(a_movement, prize)? != 0 || determinant(b_movement, prize)? != 0 {
+        return Ok(None);
+    }
+    let (a_step, b_step, distance) = if a_movement.0 != 0 || b_movement.0 != 0 {
+        (a_movement.0, b_movement.0, prize.0)
+    } else if a_movement.1 != 0 || b_movement.1 != 0 {
+        (a_movement.1, b_movement.1, prize.1)
+    } else {
+        return Ok((prize == (0, 0)).then_some(0));
+    };
+    match solve_line(a_step, b_step, distance)? {
+        Some((a, b)) => count_tokens(a, b).map(Some),
+        None => Ok(None),
+    }
+}
+
+// The cheapest non-negative presses with `a * a_step + b * b_step ==
+// distance`. The solutions are a line of steps of `b_step / g` presses of A
+// traded for `a_step / g` of B, so the cheapest is at one end of it
+fn solve_line(
+    a_step: i128,
+    b_step: i128,
+    distance: i128,
+) -> Result<Option<(i128, i128)>, Overflow> {
+    if a_step == 0 {
+        return Ok((distance % b_step == 0).then(|| (0, distance / b_step)));
+    }
+    if b_step == 0 {
+        return Ok((distance % a_step == 0).then(|| (distance / a_step, 0)));
     }
+    let (g, x, y) = extended_gcd(a_step, b_step);
+    if distance % g != 0 {
+        return Ok(None);
+    }
+    // A costs 3 tokens and B 1, so A is only worth pressing while it moves
+    // more than three times as far
+    let fewest_presses = |step: i128, coefficient: i128, other_step: i128| {
+        let period = other_step / g;
+        let presses = coefficient
+            .rem_euclid(period)
+            .checked_mul((distance / g).rem_euclid(period))
+            .ok_or(Overflow)?
+            .rem_euclid(period);
+        let rest = step
+            .checked_mul(presses)
+            .and_then(|moved| distance.checked_sub(moved))
+            .ok_or(Overflow)?;
+        Ok((rest >= 0).then(|| (presses, rest / other_step)))
+    };
+    if a_step > 3 * b_step {
+        Ok(fewest_presses(b_step, y, a_step)?.map(|(b, a)| (a, b)))
+    } else {
+        fewest_presses(a_step, x, b_step)
+    }
+}
 
-    let a = x_determinant / denominator_determinant;
-    let b = y_determinant / denominator_determinant;
-    if a.fract() != 0.0 || b.fract() != 0.0 {
-        return None;
+// `(g, x, y)` with `g` the greatest common divisor and `a * x + b * y == g`
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a, 1, 0);
     }
+    let (g, x, y) = extended_gcd(b, a % b);
+    (g, y, x - a / b * y)
+}
+
+fn count_tokens(a: i128, b: i128) -> Result<u64, Overflow> {
+    a.checked_mul(3)
+        .and_then(|a| a.checked_add(b))
+        .and_then(|tokens| u64::try_from(tokens).ok())
+        .ok_or(Overflow)
+}
+
+fn widen(vector: Vector) -> (i128, i128) {
+    (vector.0 as i128, vector.1 as i128)
+}
 
-    Some(a as u64 * 3 + b as u64)
+fn determinant(first: (i128, i128), second: (i128, i128)) -> Result<i128, Overflow> {
+    let left = first.0.checked_mul(second.1);
+    let right = second.0.checked_mul(first.1);
+    left.zip(right)
+        .and_then(|(left, right)| left.checked_sub(right))
+        .ok_or(Overflow)
 }
 
-fn adjust_prize(mut claw_machine: ClawMachine) -> ClawMachine {
-    claw_machine.prize.0 += 10000000000000;
-    claw_machine.prize.1 += 10000000000000;
-    claw_machine
+fn adjust_prize(mut claw_machine: ClawMachine) -> Result<ClawMachine, Overflow> {
+    let adjust = |coordinate: u64| coordinate.checked_add(10000000000000).ok_or(Overflow);
+    claw_machine.prize = (adjust(claw_machine.prize.0)?, adjust(claw_machine.prize.1)?);
+    Ok(claw_machine)
 }
 
 fn read_input(input: &str) -> IResult<&str, Vec<ClawMachine>> {
@@ -95,6 +202,7 @@ fn read_vector(input: &str) -> IResult<&str, Vector> {
 #[cfg(test)]
 mod tests {
     use super::*;
+
     #[test]
     fn part1_correct_output_for_test_input() {
         let contents = fs::read_to_string("test_input").unwrap();
@@ -102,6 +210,61 @@ mod tests {
         assert_eq!(result, 480);
     }
 
+    #[test]
+    fn exact_solutions() {
+        let machine = |a_movement, b_movement, prize| ClawMachine {
+            a_movement,
+            b_movement,
+            prize,
+        };
+        assert_eq!(
+            solve_claw_machine(machine((94, 34), (22, 67), (8400, 5400))),
+            Ok(Some(280))
+        );
+        assert_eq!(
+            solve_claw_machine(machine((26, 66), (67, 21), (12748, 12176))),
+            Ok(None)
+        );
+        // One press of B back would be needed
+        assert_eq!(
+            solve_claw_machine(machine((2, 1), (1, 2), (1, 5))),
+            Ok(None)
+        );
+        assert_eq!(
+            solve_claw_machine(machine((1, 2), (2, 4), (3, 6))),
+            Ok(Some(4))
+        );
+        assert_eq!(
+            solve_claw_machine(machine((6, 0), (1, 0), (12, 0))),
+            Ok(Some(6))
+        );
+        assert_eq!(
+            solve_claw_machine(machine((0, 2), (0, 3), (0, 7))),
+            Ok(Some(7))
+        );
+        assert_eq!(
+            solve_claw_machine(machine((2, 2), (4, 4), (3, 3))),
+            Ok(None)
+        );
+        assert_eq!(
+            solve_claw_machine(machine((1, 2), (2, 4), (3, 5))),
+            Ok(None)
+        );
+        assert_eq!(
+            solve_claw_machine(machine((0, 0), (0, 0), (0, 0))),
+            Ok(Some(0))
+        );
+        assert_eq!(
+            solve_claw_machine(machine((0, 0), (0, 0), (1, 0))),
+            Ok(None)
+        );
+        assert_eq!(
+            solve_claw_machine(machine((u64::MAX, 1), (1, u64::MAX), (1, 1))),
+            Err(Overflow)
+        );
+        assert!(adjust_prize(machine((1, 1), (1, 1), (u64::MAX, 0))).is_err());
+    }
+
     #[test]
     fn part1_correct_output_for_input() {
         let contents = fs::read_to_string("input").unwrap();
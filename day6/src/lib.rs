@@ -0,0 +1,758 @@
+use std::{
+    collections::HashSet,
+    ops::{Index, IndexMut},
+    thread,
+};
+
+pub type Position = (usize, usize);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Obstacle,
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    inner: Vec<Vec<Cell>>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Guard {
+    pub position: Position,
+    pub direction: Direction,
+}
+
+// A turn relative to the direction the guard is facing
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Right,
+    Left,
+    Back,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum TurnPolicy {
+    #[default]
+    Clockwise,
+    CounterClockwise,
+    Reverse,
+    // Every obstacle uses the next turn of the sequence, wrapping around.
+    // An empty sequence turns clockwise
+    Cycle(Vec<Turn>),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Boundary {
+    #[default]
+    Exit,
+    Wrap,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Rules {
+    pub turns: TurnPolicy,
+    pub boundary: Boundary,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GuardOutcome {
+    Left { steps: usize },
+    Looped { steps: usize },
+}
+
+#[derive(Debug, Clone)]
+pub struct MultiGuardReport {
+    pub visited: Vec<HashSet<Position>>,
+    // Cells visited by more than one guard, at any time
+    pub shared_cells: HashSet<Position>,
+    // Cells where several guards stood in the same tick, with the tick
+    pub meetings: Vec<(usize, Position)>,
+    pub outcomes: Vec<GuardOutcome>,
+}
+
+impl Map {
+    fn get_neighbor_in_direction(&self, pos: Position, direction: Direction) -> Option<Position> {
+        let change = match direction {
+            Direction::Up => (-1, 0),
+            Direction::Left => (0, -1),
+            Direction::Down => (1, 0),
+            Direction::Right => (0, 1),
+        };
+        if pos.0 == 0 && change.0 == -1
+            || pos.1 == 0 && change.1 == -1
+            || pos.0 == self.m() - 1 && change.0 == 1
+            || pos.1 == self.n() - 1 && change.1 == 1
+        {
+            return None;
+        }
+        Some((
+            (pos.0 as isize + change.0) as usize,
+            (pos.1 as isize + change.1) as usize,
+        ))
+    }
+
+    fn get_neighbor_wrapping(&self, pos: Position, direction: Direction) -> Position {
+        match direction {
+            Direction::Up => ((pos.0 + self.m() - 1) % self.m(), pos.1),
+            Direction::Left => (pos.0, (pos.1 + self.n() - 1) % self.n()),
+            Direction::Down => ((pos.0 + 1) % self.m(), pos.1),
+            Direction::Right => (pos.0, (pos.1 + 1) % self.n()),
+        }
+    }
+
+    // The cells from which a step in `direction` leaves the map
+    fn get_border_cells(&self, direction: Direction) -> Vec<Position> {
+        match direction {
+            Direction::Up => (0..self.n()).map(|j| (0, j)).collect(),
+            Direction::Left => (0..self.m()).map(|i| (i, 0)).collect(),
+            Direction::Down => (0..self.n()).map(|j| (self.m() - 1, j)).collect(),
+            Direction::Right => (0..self.m()).map(|i| (i, self.n() - 1)).collect(),
+        }
+    }
+
+    pub fn m(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn n(&self) -> usize {
+        self.inner[0].len()
+    }
+}
+
+impl Index<Position> for Map {
+    type Output = Cell;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        &self.inner[pos.0][pos.1]
+    }
+}
+
+impl IndexMut<Position> for Map {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        &mut self.inner[pos.0][pos.1]
+    }
+}
+
+pub fn day6_part1(input: &str) -> usize {
+    let (guard, map) = read_input(input);
+    let mut cells = get_cells_visited_before_leaving(guard, &map);
+    // Add the original pos if the guard didn't pass through it again
+    cells.insert(guard.position);
+    cells.len()
+}
+
+pub fn day6_part2(input: &str) -> usize {
+    let (guard, map) = read_input(input);
+    get_loop_obstacles(guard, &map).len()
+}
+
+// Every position where a new obstacle makes the guard loop, paired with the
+// number of forward steps in one lap of the loop, sorted by position
+pub fn get_loop_obstacles(guard: Guard, map: &Map) -> Vec<(Position, usize)> {
+    let candidates = get_states_before_entering_cells(guard, map);
+    let jumps = JumpTable::new(map);
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = candidates.len().div_ceil(threads).max(1);
+    let mut obstacles: Vec<_> = thread::scope(|scope| {
+        candidates
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let mut jumps = jumps.clone();
+                scope.spawn(move || {
+                    let mut visited = vec![(0, 0); map.m() * map.n() * 4];
+                    chunk
+                        .iter()
+                        .zip(1..)
+                        .filter_map(|(&(cell, guard), generation)| {
+                            let patch = jumps.add_obstacle(map, cell);
+                            let length = jumps.get_loop_length(guard, &mut visited, generation);
+                            jumps.revert(patch);
+                            Some((cell, length?))
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+    obstacles.sort();
+    obstacles
+}
+
+// For every cell where the guard would stand after walking straight from a
+// given cell and direction, that is, the cell before the next obstacle.
+// `None` means the guard leaves the map
+#[derive(Debug, Clone)]
+struct JumpTable {
+    n: usize,
+    stops: [Vec<Option<Position>>; 4],
+}
+
+type Patch = Vec<(Direction, Position, Option<Position>)>;
+
+impl JumpTable {
+    fn new(map: &Map) -> Self {
+        let mut jumps = JumpTable {
+            n: map.n(),
+            stops: std::array::from_fn(|_| vec![None; map.m() * map.n()]),
+        };
+        for direction in [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+        ] {
+            let opposite = get_opposite_direction(direction);
+            for pos in map.get_border_cells(direction) {
+                // Walk backwards from the border, remembering the last stop
+                let mut stop = None;
+                let mut current = Some(pos);
+                while let Some(pos) = current {
+                    if map[pos] == Cell::Obstacle {
+                        stop = map.get_neighbor_in_direction(pos, opposite);
+                    } else {
+                        jumps.set(direction, pos, stop);
+                    }
+                    current = map.get_neighbor_in_direction(pos, opposite);
+                }
+            }
+        }
+        jumps
+    }
+
+    fn get(&self, direction: Direction, pos: Position) -> Option<Position> {
+        self.stops[direction as usize][pos.0 * self.n + pos.1]
+    }
+
+    fn set(&mut self, direction: Direction, pos: Position, stop: Option<Position>) {
+        self.stops[direction as usize][pos.0 * self.n + pos.1] = stop;
+    }
+
+    // Only the cells in the same row and column as the obstacle can change,
+    // so the table is patched in place and the old values are returned
+    fn add_obstacle(&mut self, map: &Map, obstacle: Position) -> Patch {
+        let mut patch = Vec::new();
+        for direction in [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+        ] {
+            let opposite = get_opposite_direction(direction);
+            let stop = map.get_neighbor_in_direction(obstacle, opposite);
+            let mut current = stop;
+            while let Some(pos) = current {
+                if map[pos] == Cell::Obstacle {
+                    break;
+                }
+                patch.push((direction, pos, self.get(direction, pos)));
+                self.set(direction, pos, stop);
+                current = map.get_neighbor_in_direction(pos, opposite);
+            }
+        }
+        patch
+    }
+
+    fn revert(&mut self, patch: Patch) {
+        for (direction, pos, stop) in patch.into_iter().rev() {
+            self.set(direction, pos, stop);
+        }
+    }
+
+    // `visited` is shared between calls, a state counts as visited only if it
+    // was marked with the current `generation`. It also stores the number of
+    // steps taken when the state was reached, to measure the loop
+    fn get_loop_length(
+        &self,
+        mut guard: Guard,
+        visited: &mut [(u32, usize)],
+        generation: u32,
+    ) -> Option<usize> {
+        let mut steps = 0;
+        loop {
+            let stop = self.get(guard.direction, guard.position)?;
+            steps += guard.position.0.abs_diff(stop.0) + guard.position.1.abs_diff(stop.1);
+            guard.position = stop;
+            guard.direction = get_next_direction_clockwise(guard.direction);
+            let index = (stop.0 * self.n + stop.1) * 4 + guard.direction as usize;
+            if visited[index].0 == generation {
+                return Some(steps - visited[index].1);
+            }
+            visited[index] = (generation, steps);
+        }
+    }
+}
+
+// The first time the guard enters each cell, paired with the state it had
+// just before doing so. Placing an obstacle in that cell doesn't change the
+// route up to that state, so the simulation can start from there
+fn get_states_before_entering_cells(mut guard: Guard, map: &Map) -> Vec<(Position, Guard)> {
+    let mut visited = HashSet::from([guard.position]);
+    let mut states = Vec::new();
+    while let Some(next_pos) = map.get_neighbor_in_direction(guard.position, guard.direction) {
+        if map[next_pos] == Cell::Obstacle {
+            guard.direction = get_next_direction_clockwise(guard.direction);
+        } else {
+            if visited.insert(next_pos) {
+                states.push((next_pos, guard));
+            }
+            guard.position = next_pos;
+        }
+    }
+    states
+}
+
+fn get_cells_visited_before_leaving(mut guard: Guard, map: &Map) -> HashSet<Position> {
+    let mut visited = HashSet::new();
+    while let Some(next) = step(guard, map) {
+        if next.position != guard.position {
+            visited.insert(next.position);
+        }
+        guard = next;
+    }
+    visited
+}
+
+// Every state of the guard, including the starting one, until it leaves the
+// map or is about to repeat a state
+pub fn get_route(mut guard: Guard, map: &Map) -> Vec<Guard> {
+    let mut seen = HashSet::from([guard]);
+    let mut route = vec![guard];
+    while let Some(next) = step(guard, map) {
+        if !seen.insert(next) {
+            break;
+        }
+        route.push(next);
+        guard = next;
+    }
+    route
+}
+
+// The positions where the guard turned, in order
+pub fn get_turn_points(route: &[Guard]) -> Vec<Position> {
+    route
+        .windows(2)
+        .filter(|states| states[0].direction != states[1].direction)
+        .map(|states| states[1].position)
+        .collect()
+}
+
+// Draws the route over the map like the puzzle statement does, `obstacle`
+// is drawn as an `O` instead of a `#`
+pub fn render_route(map: &Map, route: &[Guard], obstacle: Option<Position>) -> String {
+    let mut vertical = HashSet::new();
+    let mut horizontal = HashSet::new();
+    for guard in route {
+        match guard.direction {
+            Direction::Up | Direction::Down => vertical.insert(guard.position),
+            Direction::Left | Direction::Right => horizontal.insert(guard.position),
+        };
+    }
+    let mut output = String::new();
+    for i in 0..map.m() {
+        for j in 0..map.n() {
+            let pos = (i, j);
+            let c = if route.first().is_some_and(|start| start.position == pos) {
+                get_guard_char(route[0].direction)
+            } else if obstacle == Some(pos) {
+                'O'
+            } else if map[pos] == Cell::Obstacle {
+                '#'
+            } else {
+                match (vertical.contains(&pos), horizontal.contains(&pos)) {
+                    (true, true) => '+',
+                    (true, false) => '|',
+                    (false, true) => '-',
+                    (false, false) => '.',
+                }
+            };
+            output.push(c);
+        }
+        output.push('\n');
+    }
+    output
+}
+
+fn get_guard_char(direction: Direction) -> char {
+    match direction {
+        Direction::Up => '^',
+        Direction::Left => '<',
+        Direction::Down => 'v',
+        Direction::Right => '>',
+    }
+}
+
+// Like `get_route`, but following `rules`. The simulation always ends: the
+// state also tracks the position in the turn sequence, so there are finitely
+// many of them, and any repeated one is a loop
+pub fn get_route_with_rules(
+    mut guard: Guard,
+    map: &Map,
+    rules: &Rules,
+) -> (Vec<Guard>, GuardOutcome) {
+    let cycle_length = match &rules.turns {
+        TurnPolicy::Cycle(turns) => turns.len().max(1),
+        _ => 1,
+    };
+    let mut turns = 0;
+    let mut seen = HashSet::from([(guard, 0)]);
+    let mut route = vec![guard];
+    while let Some(next) = step_with_rules(guard, map, rules, turns) {
+        if next.direction != guard.direction {
+            turns = (turns + 1) % cycle_length;
+        }
+        if !seen.insert((next, turns)) {
+            let steps = route.len();
+            return (route, GuardOutcome::Looped { steps });
+        }
+        route.push(next);
+        guard = next;
+    }
+    let steps = route.len() - 1;
+    (route, GuardOutcome::Left { steps })
+}
+
+// Moves every guard once per tick until all of them have left the map or
+// entered a loop. Guards don't block each other
+pub fn simulate_guards(guards: &[Guard], map: &Map) -> MultiGuardReport {
+    let mut current: Vec<Option<Guard>> = guards.iter().copied().map(Some).collect();
+    let mut visited: Vec<HashSet<Position>> = guards
+        .iter()
+        .map(|guard| HashSet::from([guard.position]))
+        .collect();
+    let mut states: Vec<HashSet<Guard>> =
+        guards.iter().map(|&guard| HashSet::from([guard])).collect();
+    let mut outcomes = vec![None; guards.len()];
+    let mut meetings = Vec::new();
+    let mut tick = 0;
+    while current.iter().any(Option::is_some) {
+        tick += 1;
+        for (i, guard) in current.iter_mut().enumerate() {
+            let Some(state) = guard else {
+                continue;
+            };
+            match step(*state, map) {
+                None => {
+                    outcomes[i] = Some(GuardOutcome::Left { steps: tick - 1 });
+                    *guard = None;
+                }
+                Some(next) if !states[i].insert(next) => {
+                    outcomes[i] = Some(GuardOutcome::Looped { steps: tick });
+                    *guard = None;
+                }
+                Some(next) => {
+                    visited[i].insert(next.position);
+                    *guard = Some(next);
+                }
+            }
+        }
+        let mut occupied = HashSet::new();
+        let mut met = HashSet::new();
+        for guard in current.iter().flatten() {
+            if !occupied.insert(guard.position) && met.insert(guard.position) {
+                meetings.push((tick, guard.position));
+            }
+        }
+    }
+    let mut seen = HashSet::new();
+    let mut shared_cells = HashSet::new();
+    for cells in &visited {
+        for &cell in cells {
+            if !seen.insert(cell) {
+                shared_cells.insert(cell);
+            }
+        }
+    }
+    MultiGuardReport {
+        visited,
+        shared_cells,
+        meetings,
+        outcomes: outcomes.into_iter().map(Option::unwrap).collect(),
+    }
+}
+
+// A single move, either turning in place or walking forward. `None` means
+// the guard left the map
+fn step(guard: Guard, map: &Map) -> Option<Guard> {
+    step_with_rules(guard, map, &Rules::default(), 0)
+}
+
+// `turns` is the number of turns taken so far, used by `TurnPolicy::Cycle`
+fn step_with_rules(guard: Guard, map: &Map, rules: &Rules, turns: usize) -> Option<Guard> {
+    let next_pos = match rules.boundary {
+        Boundary::Exit => map.get_neighbor_in_direction(guard.position, guard.direction)?,
+        Boundary::Wrap => map.get_neighbor_wrapping(guard.position, guard.direction),
+    };
+    if map[next_pos] == Cell::Obstacle {
+        let turn = match &rules.turns {
+            TurnPolicy::Cycle(sequence) if !sequence.is_empty() => sequence[turns % sequence.len()],
+            TurnPolicy::Clockwise | TurnPolicy::Cycle(_) => Turn::Right,
+            TurnPolicy::CounterClockwise => Turn::Left,
+            TurnPolicy::Reverse => Turn::Back,
+        };
+        Some(Guard {
+            position: guard.position,
+            direction: get_direction_after_turn(guard.direction, turn),
+        })
+    } else {
+        Some(Guard {
+            position: next_pos,
+            direction: guard.direction,
+        })
+    }
+}
+
+fn get_direction_after_turn(direction: Direction, turn: Turn) -> Direction {
+    match turn {
+        Turn::Right => get_next_direction_clockwise(direction),
+        Turn::Left => get_opposite_direction(get_next_direction_clockwise(direction)),
+        Turn::Back => get_opposite_direction(direction),
+    }
+}
+
+fn get_next_direction_clockwise(direction: Direction) -> Direction {
+    match direction {
+        Direction::Up => Direction::Right,
+        Direction::Left => Direction::Up,
+        Direction::Down => Direction::Left,
+        Direction::Right => Direction::Down,
+    }
+}
+
+fn get_opposite_direction(direction: Direction) -> Direction {
+    match direction {
+        Direction::Up => Direction::Down,
+        Direction::Left => Direction::Right,
+        Direction::Down => Direction::Up,
+        Direction::Right => Direction::Left,
+    }
+}
+
+pub fn read_input(input: &str) -> (Guard, Map) {
+    let (guards, map) = read_input_with_guards(input);
+    (guards[0], map)
+}
+
+pub fn read_input_with_guards(input: &str) -> (Vec<Guard>, Map) {
+    let mut guards = Vec::new();
+    let inner = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(j, c)| {
+                    let direction = match c {
+                        '^' => Direction::Up,
+                        '<' => Direction::Left,
+                        'v' => Direction::Down,
+                        '>' => Direction::Right,
+                        '#' => return Cell::Obstacle,
+                        _ => return Cell::Empty,
+                    };
+                    guards.push(Guard {
+                        position: (i, j),
+                        direction,
+                    });
+                    Cell::Empty
+                })
+                .collect()
+        })
+        .collect::<Vec<Vec<Cell>>>();
+    (guards, Map { inner })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1_correct_output_for_test_input() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let result = day6_part1(&contents);
+        assert_eq!(result, 41);
+    }
+
+    #[test]
+    fn guards_with_any_orientation_are_parsed() {
+        let (guards, _) = read_input_with_guards("^.>\n...\nv.<\n");
+        let directions: Vec<_> = guards.iter().map(|guard| guard.direction).collect();
+        assert_eq!(
+            directions,
+            [
+                Direction::Up,
+                Direction::Right,
+                Direction::Down,
+                Direction::Left
+            ]
+        );
+    }
+
+    #[test]
+    fn multiple_guards_are_simulated_independently() {
+        let input = "\
+.#.v..
+.^...#
+#.....
+....#.
+";
+        let (guards, map) = read_input_with_guards(input);
+        let report = simulate_guards(&guards, &map);
+        assert_eq!(report.outcomes[0], GuardOutcome::Left { steps: 3 });
+        assert_eq!(report.outcomes[1], GuardOutcome::Looped { steps: 12 });
+        assert_eq!(report.shared_cells, HashSet::from([(1, 3), (2, 3)]));
+        assert!(report.meetings.is_empty());
+    }
+
+    #[test]
+    fn loop_obstacles_for_test_input() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let (guard, map) = read_input(&contents);
+        let obstacles = get_loop_obstacles(guard, &map);
+        assert_eq!(
+            obstacles,
+            [
+                ((6, 3), 18),
+                ((7, 6), 12),
+                ((7, 7), 12),
+                ((8, 1), 16),
+                ((8, 3), 34),
+                ((9, 7), 14)
+            ]
+        );
+    }
+
+    #[test]
+    fn route_is_rendered_like_the_puzzle_statement() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let (guard, mut map) = read_input(&contents);
+        map[(6, 3)] = Cell::Obstacle;
+        let route = get_route(guard, &map);
+        assert_eq!(get_turn_points(&route), [(1, 4), (1, 8), (6, 8)]);
+        let expected = "\
+....#.....
+....+---+#
+....|...|.
+..#.|...|.
+....|..#|.
+....|...|.
+.#.O^---+.
+........#.
+#.........
+......#...
+";
+        assert_eq!(render_route(&map, &route, Some((6, 3))), expected);
+    }
+
+    #[test]
+    fn default_rules_match_part1() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let (guard, map) = read_input(&contents);
+        let (route, outcome) = get_route_with_rules(guard, &map, &Rules::default());
+        let cells: HashSet<_> = route.iter().map(|guard| guard.position).collect();
+        assert_eq!(cells.len(), 41);
+        assert_eq!(
+            outcome,
+            GuardOutcome::Left {
+                steps: route.len() - 1
+            }
+        );
+    }
+
+    #[test]
+    fn other_turn_policies() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let (guard, map) = read_input(&contents);
+        let rules = Rules {
+            turns: TurnPolicy::CounterClockwise,
+            boundary: Boundary::Exit,
+        };
+        let (route, _) = get_route_with_rules(guard, &map, &rules);
+        assert_eq!(get_turn_points(&route), [(1, 4)]);
+        assert_eq!(route.last().unwrap().position, (1, 0));
+
+        let rules = Rules {
+            turns: TurnPolicy::Reverse,
+            boundary: Boundary::Exit,
+        };
+        let (route, outcome) = get_route_with_rules(guard, &map, &rules);
+        assert_eq!(get_turn_points(&route), [(1, 4)]);
+        assert_eq!(route.last().unwrap().position, (9, 4));
+        assert_eq!(outcome, GuardOutcome::Left { steps: 14 });
+
+        let rules = Rules {
+            turns: TurnPolicy::Cycle(vec![Turn::Right, Turn::Left]),
+            boundary: Boundary::Exit,
+        };
+        let (route, _) = get_route_with_rules(guard, &map, &rules);
+        assert_eq!(get_turn_points(&route), [(1, 4), (1, 8)]);
+        assert_eq!(route.last().unwrap().position, (0, 8));
+
+        let rules = Rules {
+            turns: TurnPolicy::Cycle(vec![]),
+            boundary: Boundary::Exit,
+        };
+        let (route, outcome) = get_route_with_rules(guard, &map, &rules);
+        assert_eq!(route, get_route(guard, &map));
+        assert_eq!(
+            outcome,
+            GuardOutcome::Left {
+                steps: route.len() - 1
+            }
+        );
+    }
+
+    #[test]
+    fn wraparound_always_terminates() {
+        let (guard, map) = read_input("...\n.^.\n...\n");
+        let rules = Rules {
+            turns: TurnPolicy::Clockwise,
+            boundary: Boundary::Wrap,
+        };
+        let (route, outcome) = get_route_with_rules(guard, &map, &rules);
+        assert_eq!(route.len(), 3);
+        assert_eq!(outcome, GuardOutcome::Looped { steps: 3 });
+
+        let contents = fs::read_to_string("test_input").unwrap();
+        let (guard, map) = read_input(&contents);
+        let rules = Rules {
+            turns: TurnPolicy::Cycle(vec![Turn::Right, Turn::Right, Turn::Back]),
+            boundary: Boundary::Wrap,
+        };
+        let (_, outcome) = get_route_with_rules(guard, &map, &rules);
+        assert!(matches!(outcome, GuardOutcome::Looped { .. }));
+    }
+
+    #[test]
+    fn part1_correct_output_for_input() {
+        let contents = fs::read_to_string("input").unwrap();
+        let result = day6_part1(&contents);
+        assert_eq!(result, 4656);
+    }
+
+    #[test]
+    fn part2_correct_output_for_test_input() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let result = day6_part2(&contents);
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn part2_correct_output_for_input() {
+        let contents = fs::read_to_string("input").unwrap();
+        let result = day6_part2(&contents);
+        assert_eq!(result, 1575);
+    }
+}
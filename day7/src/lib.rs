@@ -0,0 +1,861 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::{multispace1, u128, u64},
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult,
+};
+use std::fmt;
+
+// The integer types equations can be solved with. Every operation is
+// checked, a branch that would overflow can't be a solution
+pub trait Number: Copy + Ord + fmt::Display + fmt::Debug {
+    const ZERO: Self;
+    const ONE: Self;
+    const TEN: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div_exact(self, other: Self) -> Option<Self>;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+    fn checked_pow(self, exponent: Self) -> Option<Self>;
+    fn xor(self, other: Self) -> Self;
+}
+
+macro_rules! impl_number {
+    ($($t:ty),*) => {
+        $(impl Number for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const TEN: Self = 10;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            fn checked_div_exact(self, other: Self) -> Option<Self> {
+                (other != 0 && self.is_multiple_of(other)).then(|| self / other)
+            }
+
+            fn checked_rem(self, other: Self) -> Option<Self> {
+                <$t>::checked_rem(self, other)
+            }
+
+            fn checked_pow(self, exponent: Self) -> Option<Self> {
+                <$t>::checked_pow(self, u32::try_from(exponent).ok()?)
+            }
+
+            fn xor(self, other: Self) -> Self {
+                self ^ other
+            }
+        })*
+    };
+}
+
+impl_number!(u64, u128);
+
+// The values of `a` for which `apply(a, b) == target`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Undo<N> {
+    // The operator can't be undone, so the numbers before it are tried
+    // forwards instead
+    NotInvertible,
+    NoSolution,
+    Exactly(N),
+    Any,
+}
+
+impl<N> From<Option<N>> for Undo<N> {
+    fn from(a: Option<N>) -> Self {
+        match a {
+            Some(a) => Undo::Exactly(a),
+            None => Undo::NoSolution,
+        }
+    }
+}
+
+// A binary operator that can be placed between the numbers of an equation
+pub trait Operator<N: Number>: fmt::Display {
+    // `None` if the result isn't defined or overflows
+    fn apply(&self, a: N, b: N) -> Option<N>;
+
+    // Lets the solver work backwards from the test value
+    fn undo(&self, _target: N, _b: N) -> Undo<N> {
+        Undo::NotInvertible
+    }
+
+    // Only used with `Order::Precedence`, higher binds tighter
+    fn precedence(&self) -> u8 {
+        0
+    }
+}
+
+impl<N: Number, O: Operator<N> + ?Sized> Operator<N> for &O {
+    fn apply(&self, a: N, b: N) -> Option<N> {
+        (**self).apply(a, b)
+    }
+
+    fn undo(&self, target: N, b: N) -> Undo<N> {
+        (**self).undo(target, b)
+    }
+
+    fn precedence(&self) -> u8 {
+        (**self).precedence()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Order {
+    #[default]
+    LeftToRight,
+    // Operators with a higher precedence are applied first, ties are
+    // applied left to right
+    Precedence,
+}
+
+pub fn day7_part1(input: &str) -> u64 {
+    let (_, equations) = read_input(input).unwrap();
+    let allowed_operations = [Operation::Addition, Operation::Multiplication];
+    get_total_calibration(&equations, &allowed_operations)
+        .expect("the calibration result doesn't fit in a u64, use read_input_wide")
+}
+
+pub fn day7_part2(input: &str) -> u64 {
+    let (_, equations) = read_input(input).unwrap();
+    let allowed_operations = [
+        Operation::Addition,
+        Operation::Multiplication,
+        Operation::Concatenation,
+    ];
+    get_total_calibration(&equations, &allowed_operations)
+        .expect("the calibration result doesn't fit in a u64, use read_input_wide")
+}
+
+// The sum of the test values of the equations that can be made true, or
+// `None` if it overflows
+pub fn get_total_calibration<N: Number, O: Operator<N>>(
+    equations: &[Equation<N>],
+    operations: &[O],
+) -> Option<N> {
+    equations
+        .iter()
+        .filter(|equation| check_equation(equation, operations))
+        .try_fold(N::ZERO, |total, equation| {
+            total.checked_add(equation.test_value)
+        })
+}
+
+pub fn check_equation<N: Number, O: Operator<N>>(equation: &Equation<N>, operations: &[O]) -> bool {
+    check_equation_with_order(equation, operations, Order::LeftToRight)
+}
+
+pub fn check_equation_with_order<N: Number, O: Operator<N>>(
+    equation: &Equation<N>,
+    operations: &[O],
+    order: Order,
+) -> bool {
+    let mut found = false;
+    search(equation, operations, order, &mut |_| {
+        found = true;
+        false
+    });
+    found
+}
+
+// Every assignment of operations that makes the equation true
+pub fn find_all_solutions<N: Number, O: Operator<N> + Clone>(
+    equation: &Equation<N>,
+    operations: &[O],
+) -> Vec<Solution<N, O>> {
+    find_all_solutions_with_order(equation, operations, Order::LeftToRight)
+}
+
+pub fn find_all_solutions_with_order<N: Number, O: Operator<N> + Clone>(
+    equation: &Equation<N>,
+    operations: &[O],
+    order: Order,
+) -> Vec<Solution<N, O>> {
+    find_solutions(equation, operations, order, usize::MAX)
+}
+
+pub fn find_solution<N: Number, O: Operator<N> + Clone>(
+    equation: &Equation<N>,
+    operations: &[O],
+) -> Option<Solution<N, O>> {
+    find_solutions(equation, operations, Order::LeftToRight, 1).pop()
+}
+
+pub fn count_solutions<N: Number, O: Operator<N>>(
+    equation: &Equation<N>,
+    operations: &[O],
+) -> usize {
+    let mut count = 0;
+    search(equation, operations, Order::LeftToRight, &mut |_| {
+        count += 1;
+        true
+    });
+    count
+}
+
+fn find_solutions<N: Number, O: Operator<N> + Clone>(
+    equation: &Equation<N>,
+    operations: &[O],
+    order: Order,
+    limit: usize,
+) -> Vec<Solution<N, O>> {
+    let mut solutions = Vec::new();
+    search(equation, operations, order, &mut |chosen| {
+        solutions.push(Solution {
+            numbers: equation.numbers.clone(),
+            operations: chosen.iter().map(|&i| operations[i].clone()).collect(),
+            order,
+        });
+        solutions.len() < limit
+    });
+    solutions
+}
+
+// Calls `found` with the indices of the operations of every solution, until
+// it returns false
+fn search<N: Number, O: Operator<N>>(
+    equation: &Equation<N>,
+    operations: &[O],
+    order: Order,
+    found: &mut impl FnMut(&[usize]) -> bool,
+) {
+    let mut chosen = Vec::new();
+    match order {
+        Order::LeftToRight => {
+            search_backwards(
+                equation.test_value,
+                &equation.numbers,
+                operations,
+                &mut chosen,
+                &mut |chosen: &[usize]| {
+                    let forwards: Vec<_> = chosen.iter().rev().copied().collect();
+                    found(&forwards)
+                },
+            );
+        }
+        Order::Precedence => {
+            search_forwards(equation, operations, &mut chosen, found);
+        }
+    }
+}
+
+// Works backwards from the test value, undoing the last operation each time.
+// Most branches die right away, since the value has to be divisible by the
+// last number, end with it, or be larger than it. Operations that can't be
+// undone, or that any value satisfies, have the numbers before them tried
+// forwards. `chosen` holds the operations from the last one backwards.
+// Returns whether to keep searching
+fn search_backwards<N: Number, O: Operator<N>>(
+    target: N,
+    numbers: &[N],
+    operations: &[O],
+    chosen: &mut Vec<usize>,
+    found: &mut impl FnMut(&[usize]) -> bool,
+) -> bool {
+    let (&last, rest) = numbers.split_last().unwrap();
+    if rest.is_empty() {
+        return target != last || found(chosen);
+    }
+    for (i, op) in operations.iter().enumerate() {
+        chosen.push(i);
+        let (&first, rest) = rest.split_first().unwrap();
+        let keep_searching = match op.undo(target, last) {
+            Undo::NoSolution => true,
+            Undo::Exactly(target) => search_backwards(
+                target,
+                &numbers[..numbers.len() - 1],
+                operations,
+                chosen,
+                found,
+            ),
+            Undo::Any => search_prefix(
+                first,
+                rest,
+                operations,
+                &|_| true,
+                chosen,
+                &mut Vec::new(),
+                found,
+            ),
+            Undo::NotInvertible => search_prefix(
+                first,
+                rest,
+                operations,
+                &|a| op.apply(a, last) == Some(target),
+                chosen,
+                &mut Vec::new(),
+                found,
+            ),
+        };
+        chosen.pop();
+        if !keep_searching {
+            return false;
+        }
+    }
+    true
+}
+
+// Tries every assignment of operations for the numbers left of a backwards
+// search, from `value` on, pruning branches that are undefined or overflow.
+// Each complete one whose value is accepted is reported together with the
+// operations already chosen backwards
+fn search_prefix<N: Number, O: Operator<N>>(
+    value: N,
+    numbers: &[N],
+    operations: &[O],
+    accept: &dyn Fn(N) -> bool,
+    backwards: &[usize],
+    prefix: &mut Vec<usize>,
+    found: &mut impl FnMut(&[usize]) -> bool,
+) -> bool {
+    let Some((&next, rest)) = numbers.split_first() else {
+        if !accept(value) {
+            return true;
+        }
+        let chosen: Vec<_> = backwards
+            .iter()
+            .chain(prefix.iter().rev())
+            .copied()
+            .collect();
+        return found(&chosen);
+    };
+    for (i, op) in operations.iter().enumerate() {
+        let Some(value) = op.apply(value, next) else {
+            continue;
+        };
+        prefix.push(i);
+        let keep_searching =
+            search_prefix(value, rest, operations, accept, backwards, prefix, found);
+        prefix.pop();
+        if !keep_searching {
+            return false;
+        }
+    }
+    true
+}
+
+// Tries every assignment of operations, evaluating the whole expression at
+// the end since precedence may change the earlier results
+fn search_forwards<N: Number, O: Operator<N>>(
+    equation: &Equation<N>,
+    operations: &[O],
+    chosen: &mut Vec<usize>,
+    found: &mut impl FnMut(&[usize]) -> bool,
+) -> bool {
+    let numbers = &equation.numbers;
+    if chosen.len() + 1 == numbers.len() {
+        let ops: Vec<_> = chosen.iter().map(|&i| &operations[i]).collect();
+        let value = evaluate(numbers, &ops, Order::Precedence);
+        return value != Some(equation.test_value) || found(chosen);
+    }
+    for i in 0..operations.len() {
+        chosen.push(i);
+        let keep_searching = search_forwards(equation, operations, chosen, found);
+        chosen.pop();
+        if !keep_searching {
+            return false;
+        }
+    }
+    true
+}
+
+fn evaluate<N: Number, O: Operator<N>>(numbers: &[N], operations: &[O], order: Order) -> Option<N> {
+    let mut pairs = operations.iter().zip(&numbers[1..]);
+    match order {
+        Order::LeftToRight => {
+            pairs.try_fold(numbers[0], |acc, (op, &number)| op.apply(acc, number))
+        }
+        Order::Precedence => {
+            let mut values = vec![numbers[0]];
+            let mut pending: Vec<&O> = Vec::new();
+            let reduce = |values: &mut Vec<N>, op: &O| {
+                let b = values.pop().unwrap();
+                let a = values.pop().unwrap();
+                values.push(op.apply(a, b)?);
+                Some(())
+            };
+            for (op, &number) in pairs {
+                while let Some(previous) =
+                    pending.pop_if(|previous| previous.precedence() >= op.precedence())
+                {
+                    reduce(&mut values, previous)?;
+                }
+                pending.push(op);
+                values.push(number);
+            }
+            while let Some(previous) = pending.pop() {
+                reduce(&mut values, previous)?;
+            }
+            values.pop()
+        }
+    }
+}
+
+pub fn read_input(input: &str) -> IResult<&str, Vec<Equation>> {
+    read_equations(input, |input| u64(input))
+}
+
+// Like `read_input`, for inputs with values too large for a u64
+pub fn read_input_wide(input: &str) -> IResult<&str, Vec<Equation<u128>>> {
+    read_equations(input, |input| u128(input))
+}
+
+fn read_equations<N: Number>(
+    input: &str,
+    number: fn(&str) -> IResult<&str, N>,
+) -> IResult<&str, Vec<Equation<N>>> {
+    separated_list1(multispace1, |input| read_equation(input, number))(input)
+}
+
+fn read_equation<N: Number>(
+    input: &str,
+    number: fn(&str) -> IResult<&str, N>,
+) -> IResult<&str, Equation<N>> {
+    let (input, (test_value, numbers)) =
+        separated_pair(number, tag(": "), separated_list1(tag(" "), number))(input)?;
+
+    Ok((
+        input,
+        Equation {
+            test_value,
+            numbers,
+        },
+    ))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Equation<N = u64> {
+    pub test_value: N,
+    pub numbers: Vec<N>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Addition,
+    Multiplication,
+    Concatenation,
+}
+
+impl<N: Number> Operator<N> for Operation {
+    fn apply(&self, a: N, b: N) -> Option<N> {
+        match self {
+            Operation::Addition => a.checked_add(b),
+            Operation::Multiplication => a.checked_mul(b),
+            Operation::Concatenation => ConcatenationInBase(N::TEN).apply(a, b),
+        }
+    }
+
+    fn undo(&self, target: N, b: N) -> Undo<N> {
+        match self {
+            Operation::Addition => target.checked_sub(b).into(),
+            // Any `a` times zero is zero
+            Operation::Multiplication if b == N::ZERO && target == N::ZERO => Undo::Any,
+            Operation::Multiplication => target.checked_div_exact(b).into(),
+            Operation::Concatenation => ConcatenationInBase(N::TEN).undo(target, b),
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Operation::Addition => 1,
+            Operation::Multiplication => 2,
+            Operation::Concatenation => 4,
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = match self {
+            Operation::Addition => "+",
+            Operation::Multiplication => "*",
+            Operation::Concatenation => "||",
+        };
+        write!(f, "{symbol}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Subtraction;
+
+impl<N: Number> Operator<N> for Subtraction {
+    fn apply(&self, a: N, b: N) -> Option<N> {
+        a.checked_sub(b)
+    }
+
+    fn undo(&self, target: N, b: N) -> Undo<N> {
+        target.checked_add(b).into()
+    }
+
+    fn precedence(&self) -> u8 {
+        1
+    }
+}
+
+impl fmt::Display for Subtraction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "-")
+    }
+}
+
+// Only defined when `b` divides `a`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExactDivision;
+
+impl<N: Number> Operator<N> for ExactDivision {
+    fn apply(&self, a: N, b: N) -> Option<N> {
+        a.checked_div_exact(b)
+    }
+
+    fn undo(&self, target: N, b: N) -> Undo<N> {
+        if b == N::ZERO {
+            return Undo::NoSolution;
+        }
+        target.checked_mul(b).into()
+    }
+
+    fn precedence(&self) -> u8 {
+        2
+    }
+}
+
+impl fmt::Display for ExactDivision {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "/")
+    }
+}
+
+// Has no inverse, so the numbers before it are searched forwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Power;
+
+impl<N: Number> Operator<N> for Power {
+    fn apply(&self, a: N, b: N) -> Option<N> {
+        a.checked_pow(b)
+    }
+
+    fn precedence(&self) -> u8 {
+        3
+    }
+}
+
+impl fmt::Display for Power {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "^")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Xor;
+
+impl<N: Number> Operator<N> for Xor {
+    fn apply(&self, a: N, b: N) -> Option<N> {
+        Some(a.xor(b))
+    }
+
+    fn undo(&self, target: N, b: N) -> Undo<N> {
+        Undo::Exactly(target.xor(b))
+    }
+}
+
+impl fmt::Display for Xor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "xor")
+    }
+}
+
+// Appends the digits of `b` to `a`, both written in the given base
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConcatenationInBase<N>(pub N);
+
+impl<N: Number> Operator<N> for ConcatenationInBase<N> {
+    fn apply(&self, a: N, b: N) -> Option<N> {
+        a.checked_mul(get_power_above(self.0, b)?)?.checked_add(b)
+    }
+
+    fn undo(&self, target: N, b: N) -> Undo<N> {
+        let undo = || {
+            let power = get_power_above(self.0, b)?;
+            if target.checked_rem(power)? != b {
+                return None;
+            }
+            target.checked_sub(b)?.checked_div_exact(power)
+        };
+        undo().into()
+    }
+
+    fn precedence(&self) -> u8 {
+        4
+    }
+}
+
+impl<N: Number> fmt::Display for ConcatenationInBase<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "||{}", self.0)
+    }
+}
+
+// The smallest power of `base` greater than `n`, that is, `base` to the
+// number of digits of `n`. `None` if it doesn't fit, or if the base has no
+// digits because it is below 2
+fn get_power_above<N: Number>(base: N, n: N) -> Option<N> {
+    if base <= N::ONE {
+        return None;
+    }
+    let mut power = base;
+    while power <= n {
+        power = power.checked_mul(base)?;
+    }
+    Some(power)
+}
+
+// The numbers of an equation with the operations placed between them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution<N = u64, O = Operation> {
+    pub numbers: Vec<N>,
+    pub operations: Vec<O>,
+    pub order: Order,
+}
+
+impl<N: Number, O: Operator<N>> Solution<N, O> {
+    // `None` if any intermediate value is undefined or overflows
+    pub fn evaluate(&self) -> Option<N> {
+        evaluate(&self.numbers, &self.operations, self.order)
+    }
+}
+
+impl<N: Number, O: Operator<N>> fmt::Display for Solution<N, O> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.numbers[0])?;
+        for (op, number) in self.operations.iter().zip(&self.numbers[1..]) {
+            write!(f, " {op} {number}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1_correct_output_for_test_input() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let result = day7_part1(&contents);
+        assert_eq!(result, 3749);
+    }
+
+    #[test]
+    fn solutions_are_printed_as_expressions() {
+        let (_, equations) = read_input(&fs::read_to_string("test_input").unwrap()).unwrap();
+        let operations = [Operation::Addition, Operation::Multiplication];
+        let solutions: Vec<_> = find_all_solutions(&equations[1], &operations)
+            .iter()
+            .map(|solution| solution.to_string())
+            .collect();
+        assert_eq!(solutions, ["81 * 40 + 27", "81 + 40 * 27"]);
+        assert_eq!(find_solution(&equations[2], &operations), None);
+
+        let operations = [
+            Operation::Addition,
+            Operation::Multiplication,
+            Operation::Concatenation,
+        ];
+        let solution = find_solution(&equations[4], &operations).unwrap();
+        assert_eq!(solution.to_string(), "6 * 8 || 6 * 15");
+        assert_eq!(solution.evaluate(), Some(7290));
+        assert_eq!(count_solutions(&equations[4], &operations), 1);
+    }
+
+    #[test]
+    fn overflowing_branches_are_pruned() {
+        let (_, equations) = read_input("18446744073709551615: 1 18446744073709551615").unwrap();
+        let operations = [
+            Operation::Addition,
+            Operation::Multiplication,
+            Operation::Concatenation,
+        ];
+        assert!(check_equation(&equations[0], &operations));
+        let solution = Solution {
+            numbers: vec![u64::MAX, 2],
+            operations: vec![Operation::Multiplication],
+            order: Order::LeftToRight,
+        };
+        assert_eq!(solution.evaluate(), None);
+        let (_, equations) = read_input("1: 18446744073709551615 2").unwrap();
+        assert_eq!(get_total_calibration(&equations, &operations), Some(0));
+    }
+
+    #[test]
+    fn zeros_are_multiplied() {
+        let (_, equations) = read_input("7: 3 0 7\n0: 5 0\n0: 0 0").unwrap();
+        let operations = [Operation::Addition, Operation::Multiplication];
+        assert!(check_equation(&equations[0], &operations));
+        assert_eq!(count_solutions(&equations[0], &operations), 1);
+        assert!(check_equation(&equations[1], &operations));
+        assert_eq!(count_solutions(&equations[1], &operations), 1);
+        let solutions: Vec<_> = find_all_solutions(&equations[2], &operations)
+            .iter()
+            .map(|solution| solution.to_string())
+            .collect();
+        assert_eq!(solutions, ["0 + 0", "0 * 0"]);
+    }
+
+    #[test]
+    fn custom_operators_can_be_undone_by_any_value() {
+        // Keeps the right operand, so any left one works
+        struct Right;
+
+        impl fmt::Display for Right {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "->")
+            }
+        }
+
+        impl Operator<u64> for Right {
+            fn apply(&self, _a: u64, b: u64) -> Option<u64> {
+                Some(b)
+            }
+
+            fn undo(&self, target: u64, b: u64) -> Undo<u64> {
+                if target == b {
+                    Undo::Any
+                } else {
+                    Undo::NoSolution
+                }
+            }
+        }
+
+        let (_, equations) = read_input("7: 2 3 7\n7: 2 3 4\n8: 1 2 3").unwrap();
+        let operations: [&dyn Operator<u64>; 2] = [&Operation::Addition, &Right];
+        assert_eq!(count_solutions(&equations[0], &operations), 2);
+        let solutions: Vec<_> = find_all_solutions(&equations[1], &operations)
+            .iter()
+            .map(|solution| solution.to_string())
+            .collect();
+        assert_eq!(solutions, ["2 -> 3 + 4"]);
+        let operations: [&dyn Operator<u64>; 2] = [&Operation::Multiplication, &Power];
+        assert!(!check_equation(&equations[1], &operations));
+        assert!(check_equation(&equations[2], &operations));
+    }
+
+    #[test]
+    fn wide_values_are_supported() {
+        let input = "\
+100000000000000000000000: 100000000000 1000000000000
+12345678901234567890123: 1234567890 1234567890123
+";
+        let (_, equations) = read_input_wide(input).unwrap();
+        let operations = [
+            Operation::Addition,
+            Operation::Multiplication,
+            Operation::Concatenation,
+        ];
+        let solutions: Vec<_> = equations
+            .iter()
+            .map(|equation| find_solution(equation, &operations).unwrap().to_string())
+            .collect();
+        assert_eq!(
+            solutions,
+            [
+                "100000000000 * 1000000000000",
+                "1234567890 || 1234567890123"
+            ]
+        );
+        assert_eq!(
+            get_total_calibration(&equations, &operations),
+            Some(112345678901234567890123)
+        );
+    }
+
+    #[test]
+    fn custom_operators_are_searched_backwards_when_invertible() {
+        let (_, equations) = read_input("4: 20 5 2 2\n6: 5 3\n29: 3 5").unwrap();
+        let operations: [&dyn Operator<u64>; 4] = [
+            &Operation::Addition,
+            &Subtraction,
+            &Operation::Multiplication,
+            &ExactDivision,
+        ];
+        let solutions = find_all_solutions(&equations[0], &operations);
+        let expressions: Vec<_> = solutions.iter().map(|s| s.to_string()).collect();
+        assert!(expressions.contains(&"20 / 5 + 2 - 2".to_string()));
+        assert!(expressions.contains(&"20 / 5 * 2 / 2".to_string()));
+        assert!(solutions
+            .iter()
+            .all(|solution| solution.evaluate() == Some(4)));
+        assert_eq!(count_solutions(&equations[0], &operations), solutions.len());
+
+        let solution = find_solution(&equations[1], &[Xor]).unwrap();
+        assert_eq!(solution.to_string(), "5 xor 3");
+        let solution = find_solution(&equations[2], &[ConcatenationInBase(2)]).unwrap();
+        assert_eq!(solution.to_string(), "3 ||2 5");
+        for base in [0u64, 1] {
+            assert_eq!(ConcatenationInBase(base).apply(5, 3), None);
+            assert_eq!(ConcatenationInBase(base).undo(53, 3), Undo::NoSolution);
+            assert!(!check_equation(&equations[2], &[ConcatenationInBase(base)]));
+        }
+    }
+
+    #[test]
+    fn operators_without_inverse_and_precedence_order() {
+        let (_, equations) = read_input("81: 3 2 2\n3267: 81 40 27").unwrap();
+        let operations: [&dyn Operator<u64>; 2] = [&Power, &Operation::Multiplication];
+        let solutions: Vec<_> = find_all_solutions(&equations[0], &operations)
+            .iter()
+            .map(|solution| solution.to_string())
+            .collect();
+        assert_eq!(solutions, ["3 ^ 2 ^ 2"]);
+
+        let operations = [Operation::Addition, Operation::Multiplication];
+        let solutions: Vec<_> =
+            find_all_solutions_with_order(&equations[1], &operations, Order::Precedence)
+                .iter()
+                .map(|solution| solution.to_string())
+                .collect();
+        assert_eq!(solutions, ["81 * 40 + 27"]);
+        assert!(!check_equation_with_order(
+            &Equation::<u64> {
+                test_value: 292,
+                numbers: vec![11, 6, 16, 20]
+            },
+            &operations,
+            Order::Precedence
+        ));
+    }
+
+    #[test]
+    fn part1_correct_output_for_input() {
+        let contents = fs::read_to_string("input").unwrap();
+        let result = day7_part1(&contents);
+        assert_eq!(result, 28730327770375);
+    }
+
+    #[test]
+    fn part2_correct_output_for_test_input() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let result = day7_part2(&contents);
+        assert_eq!(result, 11387);
+    }
+
+    #[test]
+    fn part2_correct_output_for_input() {
+        let contents = fs::read_to_string("input").unwrap();
+        let result = day7_part2(&contents);
+        assert_eq!(result, 424977609625985);
+    }
+}
@@ -0,0 +1,459 @@
+use std::collections::{HashMap, HashSet};
+
+pub type Position = (isize, isize);
+
+// How far apart the harmonic antinodes of a pair are
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Harmonics {
+    // The distance between the two antennas
+    Distance,
+    // The distance divided by its gcd, so every grid cell on the line is hit
+    Lattice,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AntennaMap {
+    pub antennas: HashMap<char, Vec<Position>>,
+    pub m: usize,
+    pub n: usize,
+}
+
+// An antinode lies on the line through a pair of antennas, `ratio` times
+// the distance between them away from one antenna, on the opposite side
+// from the other one. A ratio of 1 gives part 1, negative ratios fall
+// between the antennas
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ratios {
+    // Fractions as (numerator, denominator), only the ones landing exactly
+    // on a grid cell count. Fractions with a zero denominator are ignored
+    Fractions(Vec<(isize, isize)>),
+    // 0, 1, 2... until leaving the map, like part 2
+    Multiples,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AntinodeRules {
+    pub ratios: Ratios,
+    // Manhattan distance from the antenna the antinode is measured from
+    pub max_distance: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Antinode {
+    pub position: Position,
+    pub antennas: (Position, Position),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RenderOptions {
+    // Only draw the antinodes of these frequencies, all of them if `None`
+    pub frequencies: Option<HashSet<char>>,
+    // Colour each frequency and its antinodes with ANSI escape codes
+    pub colour: bool,
+}
+
+pub fn day8_part1(input: &str) -> usize {
+    let antennas = read_antennas(input);
+    let m = input.lines().count();
+    let n = input.lines().next().unwrap().len();
+    antennas
+        .into_values()
+        .flat_map(|antennas| get_antinodes_for_type(&antennas, m, n))
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+pub fn day8_part2(input: &str) -> usize {
+    count_harmonic_antinodes(input, Harmonics::Distance)
+}
+
+pub fn count_harmonic_antinodes(input: &str, harmonics: Harmonics) -> usize {
+    let antennas = read_antennas(input);
+    let m = input.lines().count();
+    let n = input.lines().next().unwrap().len();
+    antennas
+        .into_values()
+        .flat_map(|antennas| get_harmonic_antinodes_for_type(&antennas, m, n, harmonics))
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+fn get_antinodes_for_type(antennas: &[Position], m: usize, n: usize) -> Vec<Position> {
+    antennas
+        .iter()
+        .enumerate()
+        .flat_map(|(index, &pos1)| antennas[index + 1..].iter().map(move |&pos2| (pos1, pos2)))
+        .flat_map(|(pos1, pos2)| get_antinodes_for_pair(pos1, pos2, m, n))
+        .collect()
+}
+
+fn get_antinodes_for_pair(pos1: Position, pos2: Position, m: usize, n: usize) -> Vec<Position> {
+    let distance = (pos1.0 - pos2.0, pos1.1 - pos2.1);
+    let antinode1 = (pos1.0 + distance.0, pos1.1 + distance.1);
+    let antinode2 = (pos2.0 - distance.0, pos2.1 - distance.1);
+    [antinode1, antinode2]
+        .into_iter()
+        .filter(|&pos| check_bounds(pos, m, n))
+        .collect()
+}
+
+fn get_harmonic_antinodes_for_type(
+    antennas: &[Position],
+    m: usize,
+    n: usize,
+    harmonics: Harmonics,
+) -> Vec<Position> {
+    antennas
+        .iter()
+        .enumerate()
+        .flat_map(|(index, &pos1)| antennas[index + 1..].iter().map(move |&pos2| (pos1, pos2)))
+        .flat_map(|(pos1, pos2)| get_harmonic_antinodes_for_pair(pos1, pos2, m, n, harmonics))
+        .collect()
+}
+
+fn get_harmonic_antinodes_for_pair(
+    pos1: Position,
+    pos2: Position,
+    m: usize,
+    n: usize,
+    harmonics: Harmonics,
+) -> Vec<Position> {
+    let mut step = (pos1.0 - pos2.0, pos1.1 - pos2.1);
+    if harmonics == Harmonics::Lattice {
+        let divisor = gcd(step.0.abs(), step.1.abs());
+        step = (step.0 / divisor, step.1 / divisor);
+    }
+    // Walking from one antenna in both directions also goes through the other
+    let mut antinodes = vec![pos1];
+    for step in [step, (-step.0, -step.1)] {
+        let mut antinode = (pos1.0 + step.0, pos1.1 + step.1);
+        while check_bounds(antinode, m, n) {
+            antinodes.push(antinode);
+            antinode = (antinode.0 + step.0, antinode.1 + step.1);
+        }
+    }
+    antinodes
+}
+
+fn gcd(a: isize, b: isize) -> isize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// Every antinode produced by each pair of antennas of the same frequency.
+// An antinode appears once for each pair that produces it
+pub fn get_antinodes(map: &AntennaMap, rules: &AntinodeRules) -> HashMap<char, Vec<Antinode>> {
+    map.antennas
+        .iter()
+        .map(|(&frequency, antennas)| {
+            let antinodes = antennas
+                .iter()
+                .enumerate()
+                .flat_map(|(index, &pos1)| {
+                    antennas[index + 1..].iter().map(move |&pos2| (pos1, pos2))
+                })
+                .flat_map(|(pos1, pos2)| {
+                    let mut positions = get_antinodes_with_rules(pos1, pos2, map, rules);
+                    positions.extend(get_antinodes_with_rules(pos2, pos1, map, rules));
+                    positions.into_iter().map(move |position| Antinode {
+                        position,
+                        antennas: (pos1, pos2),
+                    })
+                })
+                .collect();
+            (frequency, antinodes)
+        })
+        .collect()
+}
+
+// The antinodes measured from `from`, on the side away from `other`
+fn get_antinodes_with_rules(
+    from: Position,
+    other: Position,
+    map: &AntennaMap,
+    rules: &AntinodeRules,
+) -> Vec<Position> {
+    let distance = (from.0 - other.0, from.1 - other.1);
+    let within_distance = |pos: Position| {
+        rules.max_distance.is_none_or(|max_distance| {
+            from.0.abs_diff(pos.0) + from.1.abs_diff(pos.1) <= max_distance
+        })
+    };
+    let is_valid = |pos: Position| check_bounds(pos, map.m, map.n) && within_distance(pos);
+    match &rules.ratios {
+        Ratios::Fractions(fractions) => fractions
+            .iter()
+            .filter(|&&(numerator, denominator)| {
+                denominator != 0
+                    && (distance.0 * numerator) % denominator == 0
+                    && (distance.1 * numerator) % denominator == 0
+            })
+            .map(|&(numerator, denominator)| {
+                (
+                    from.0 + distance.0 * numerator / denominator,
+                    from.1 + distance.1 * numerator / denominator,
+                )
+            })
+            .filter(|&pos| is_valid(pos))
+            .collect(),
+        Ratios::Multiples => (0..)
+            .map(|k| (from.0 + distance.0 * k, from.1 + distance.1 * k))
+            .take_while(|&pos| is_valid(pos))
+            .collect(),
+    }
+}
+
+// Draws the map like the puzzle examples, with a `#` for every antinode not
+// covered by an antenna
+pub fn render_antinodes(
+    map: &AntennaMap,
+    antinodes: &HashMap<char, Vec<Antinode>>,
+    options: &RenderOptions,
+) -> String {
+    let mut frequencies: Vec<_> = map.antennas.keys().copied().collect();
+    frequencies.sort();
+    let mut grid = vec![vec![('.', None); map.n]; map.m];
+    // Go through the frequencies in order so that a cell shared by several of
+    // them is always drawn with the colour of the last one
+    for &frequency in &frequencies {
+        let Some(antinodes) = antinodes.get(&frequency) else {
+            continue;
+        };
+        if options
+            .frequencies
+            .as_ref()
+            .is_some_and(|selected| !selected.contains(&frequency))
+        {
+            continue;
+        }
+        for antinode in antinodes {
+            let (i, j) = antinode.position;
+            grid[i as usize][j as usize] = ('#', Some(frequency));
+        }
+    }
+    for (&frequency, antennas) in &map.antennas {
+        for &(i, j) in antennas {
+            grid[i as usize][j as usize] = (frequency, Some(frequency));
+        }
+    }
+    let mut output = String::new();
+    for row in grid {
+        for (c, frequency) in row {
+            match frequency.filter(|_| options.colour) {
+                Some(frequency) => {
+                    let index = frequencies.binary_search(&frequency).unwrap_or(0);
+                    let colour = 31 + index % 6;
+                    output.push_str(&format!("\x1b[{colour}m{c}\x1b[0m"));
+                }
+                None => output.push(c),
+            }
+        }
+        output.push('\n');
+    }
+    output
+}
+
+fn check_bounds(pos: Position, m: usize, n: usize) -> bool {
+    pos.0 >= 0 && pos.0 < m as isize && pos.1 >= 0 && pos.1 < n as isize
+}
+
+pub fn read_map(input: &str) -> AntennaMap {
+    AntennaMap {
+        antennas: read_antennas(input),
+        m: input.lines().count(),
+        n: input.lines().next().unwrap().len(),
+    }
+}
+
+pub fn read_antennas(input: &str) -> HashMap<char, Vec<Position>> {
+    let mut antennas = HashMap::new();
+    for (i, line) in input.lines().enumerate() {
+        for (j, c) in line.chars().enumerate() {
+            if c == '.' {
+                continue;
+            }
+            let entry = antennas.entry(c).or_insert_with(Vec::new);
+            entry.push((i as isize, j as isize));
+        }
+    }
+    antennas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1_correct_output_for_test_input() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let result = day8_part1(&contents);
+        assert_eq!(result, 14);
+    }
+
+    #[test]
+    fn lattice_harmonics_hit_every_cell_on_the_line() {
+        let input = "\
+a.........
+..........
+..........
+..........
+......a...
+..........
+..........
+..........
+..........
+..........
+";
+        let mut antinodes =
+            get_harmonic_antinodes_for_pair((0, 0), (4, 6), 10, 10, Harmonics::Distance);
+        antinodes.sort();
+        assert_eq!(antinodes, [(0, 0), (4, 6)]);
+        let mut antinodes =
+            get_harmonic_antinodes_for_pair((0, 0), (4, 6), 10, 10, Harmonics::Lattice);
+        antinodes.sort();
+        assert_eq!(antinodes, [(0, 0), (2, 3), (4, 6), (6, 9)]);
+        assert_eq!(count_harmonic_antinodes(input, Harmonics::Distance), 2);
+        assert_eq!(count_harmonic_antinodes(input, Harmonics::Lattice), 4);
+    }
+
+    #[test]
+    fn antinode_rules_reproduce_both_parts() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let map = read_map(&contents);
+        let count = |rules: &AntinodeRules| {
+            get_antinodes(&map, rules)
+                .into_values()
+                .flatten()
+                .map(|antinode| antinode.position)
+                .collect::<HashSet<_>>()
+                .len()
+        };
+        let part1 = AntinodeRules {
+            ratios: Ratios::Fractions(vec![(1, 1)]),
+            max_distance: None,
+        };
+        assert_eq!(count(&part1), 14);
+        let part2 = AntinodeRules {
+            ratios: Ratios::Multiples,
+            max_distance: None,
+        };
+        assert_eq!(count(&part2), 34);
+        let limited = AntinodeRules {
+            ratios: Ratios::Multiples,
+            max_distance: Some(0),
+        };
+        assert_eq!(count(&limited), 7);
+    }
+
+    #[test]
+    fn antinodes_are_grouped_with_their_antennas() {
+        let map = read_map("...\n...\n...\n..a\n...\n...\n..a\n...\n...\n...\n");
+        let rules = AntinodeRules {
+            ratios: Ratios::Fractions(vec![(1, 1), (1, 2), (-1, 3), (1, 0)]),
+            max_distance: None,
+        };
+        let antinodes = get_antinodes(&map, &rules);
+        let mut positions: Vec<_> = antinodes[&'a']
+            .iter()
+            .map(|antinode| antinode.position)
+            .collect();
+        positions.sort();
+        assert_eq!(positions, [(0, 2), (4, 2), (5, 2), (9, 2)]);
+        assert!(antinodes[&'a']
+            .iter()
+            .all(|antinode| antinode.antennas == ((3, 2), (6, 2))));
+    }
+
+    #[test]
+    fn antinodes_are_rendered_like_the_puzzle_example() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let map = read_map(&contents);
+        let rules = AntinodeRules {
+            ratios: Ratios::Fractions(vec![(1, 1)]),
+            max_distance: None,
+        };
+        let antinodes = get_antinodes(&map, &rules);
+        let expected = "\
+......#....#
+...#....0...
+....#0....#.
+..#....0....
+....0....#..
+.#....A.....
+...#........
+#......#....
+........A...
+.........A..
+..........#.
+..........#.
+";
+        assert_eq!(
+            render_antinodes(&map, &antinodes, &RenderOptions::default()),
+            expected
+        );
+
+        let options = RenderOptions {
+            frequencies: Some(HashSet::from(['A'])),
+            colour: false,
+        };
+        let rendered = render_antinodes(&map, &antinodes, &options);
+        assert_eq!(rendered.matches('#').count(), 5);
+
+        let options = RenderOptions {
+            frequencies: None,
+            colour: true,
+        };
+        let rendered = render_antinodes(&map, &antinodes, &options);
+        assert!(rendered.contains("\x1b[31m0\x1b[0m"));
+        assert!(rendered.contains("\x1b[32mA\x1b[0m"));
+        assert_eq!(
+            rendered
+                .replace("\x1b[31m", "")
+                .replace("\x1b[32m", "")
+                .replace("\x1b[0m", ""),
+            expected
+        );
+    }
+
+    #[test]
+    fn shared_antinodes_are_coloured_by_the_last_frequency() {
+        let map = read_map(".aa.\nb...\nb...\n....\n");
+        let rules = AntinodeRules {
+            ratios: Ratios::Fractions(vec![(1, 1)]),
+            max_distance: None,
+        };
+        let options = RenderOptions {
+            frequencies: None,
+            colour: true,
+        };
+        for _ in 0..20 {
+            let antinodes = get_antinodes(&map, &rules);
+            let rendered = render_antinodes(&map, &antinodes, &options);
+            assert!(rendered.starts_with("\x1b[32m#\x1b[0m"));
+        }
+    }
+
+    #[test]
+    fn part1_correct_output_for_input() {
+        let contents = fs::read_to_string("input").unwrap();
+        let result = day8_part1(&contents);
+        assert_eq!(result, 285);
+    }
+    #[test]
+    fn part2_correct_output_for_test_input() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let result = day8_part2(&contents);
+        assert_eq!(result, 34);
+    }
+
+    #[test]
+    fn part2_correct_output_for_input() {
+        let contents = fs::read_to_string("input").unwrap();
+        let result = day8_part2(&contents);
+        assert_eq!(result, 944);
+    }
+}
@@ -1,9 +1,5 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fs,
-};
-
-type Position = (isize, isize);
+use day8::{day8_part1, day8_part2};
+use std::fs;
 
 fn main() {
     let contents = fs::read_to_string("input").unwrap();
@@ -12,123 +8,3 @@ fn main() {
     let result = day8_part2(&contents);
     println!("Day8 part 2 result: {result}");
 }
-
-fn day8_part1(input: &str) -> usize {
-    let antennas = read_antennas(input);
-    let m = input.lines().count();
-    let n = input.lines().next().unwrap().len();
-    antennas
-        .into_iter()
-        .flat_map(|(_, antennas)| get_antinodes_for_type(&antennas, m, n))
-        .collect::<HashSet<_>>()
-        .len()
-}
-
-fn day8_part2(input: &str) -> usize {
-    let antennas = read_antennas(input);
-    let m = input.lines().count();
-    let n = input.lines().next().unwrap().len();
-    antennas
-        .into_iter()
-        .flat_map(|(_, antennas)| get_harmonic_antinodes_for_type(&antennas, m, n))
-        .collect::<HashSet<_>>()
-        .len()
-}
-
-fn get_antinodes_for_type(antennas: &[Position], m: usize, n: usize) -> Vec<Position> {
-    antennas
-        .iter()
-        .enumerate()
-        .flat_map(|(index, &pos1)| antennas[index + 1..].iter().map(move |&pos2| (pos1, pos2)))
-        .flat_map(|(pos1, pos2)| get_antinodes_for_pair(pos1, pos2, m, n))
-        .collect()
-}
-
-fn get_antinodes_for_pair(pos1: Position, pos2: Position, m: usize, n: usize) -> Vec<Position> {
-    let distance = (pos1.0 - pos2.0, pos1.1 - pos2.1);
-    let antinode1 = (pos1.0 + distance.0, pos1.1 + distance.1);
-    let antinode2 = (pos2.0 - distance.0, pos2.1 - distance.1);
-    [antinode1, antinode2]
-        .into_iter()
-        .filter(|&pos| check_bounds(pos, m, n))
-        .collect()
-}
-
-fn get_harmonic_antinodes_for_type(antennas: &[Position], m: usize, n: usize) -> Vec<Position> {
-    antennas
-        .iter()
-        .enumerate()
-        .flat_map(|(index, &pos1)| antennas[index + 1..].iter().map(move |&pos2| (pos1, pos2)))
-        .flat_map(|(pos1, pos2)| get_harmonic_antinodes_for_pair(pos1, pos2, m, n))
-        .collect()
-}
-
-fn get_harmonic_antinodes_for_pair(
-    pos1: Position,
-    pos2: Position,
-    m: usize,
-    n: usize,
-) -> Vec<Position> {
-    let mut antinodes = vec![pos1, pos2];
-    let distance = (pos1.0 - pos2.0, pos1.1 - pos2.1);
-    let mut antinode = (pos1.0 + distance.0, pos1.1 + distance.1);
-    while check_bounds(antinode, m, n) {
-        antinodes.push(antinode);
-        antinode = (antinode.0 + distance.0, antinode.1 + distance.1);
-    }
-    antinode = (pos2.0 - distance.0, pos2.1 - distance.1);
-    while check_bounds(antinode, m, n) {
-        antinodes.push(antinode);
-        antinode = (antinode.0 - distance.0, antinode.1 - distance.1);
-    }
-    antinodes
-}
-
-fn check_bounds(pos: Position, m: usize, n: usize) -> bool {
-    pos.0 >= 0 && pos.0 < m as isize && pos.1 >= 0 && pos.1 < n as isize
-}
-
-fn read_antennas(input: &str) -> HashMap<char, Vec<Position>> {
-    let mut antennas = HashMap::new();
-    for (i, line) in input.lines().enumerate() {
-        for (j, c) in line.chars().enumerate() {
-            if c == '.' {
-                continue;
-            }
-            let entry = antennas.entry(c).or_insert(Vec::new());
-            entry.push((i as isize, j as isize));
-        }
-    }
-    antennas
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn part1_correct_output_for_test_input() {
-        let contents = fs::read_to_string("test_input").unwrap();
-        let result = day8_part1(&contents);
-        assert_eq!(result, 14);
-    }
-
-    #[test]
-    fn part1_correct_output_for_input() {
-        let contents = fs::read_to_string("input").unwrap();
-        let result = day8_part1(&contents);
-        assert_eq!(result, 285);
-    }
-    #[test]
-    fn part2_correct_output_for_test_input() {
-        let contents = fs::read_to_string("test_input").unwrap();
-        let result = day8_part2(&contents);
-        assert_eq!(result, 34);
-    }
-
-    #[test]
-    fn part2_correct_output_for_input() {
-        let contents = fs::read_to_string("input").unwrap();
-        let result = day8_part2(&contents);
-        assert_eq!(result, 944);
-    }
-}
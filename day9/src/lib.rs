@@ -0,0 +1,597 @@
+use part2::BlockGroup;
+use std::{error::Error, fmt, str::FromStr};
+
+// The lengths of the entries of a dense disk map, alternating between files
+// and free spans, starting with a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskMap {
+    pub lengths: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiskMapError {
+    Empty,
+    InvalidDigit { index: usize, found: char },
+}
+
+// A dense disk map doesn't store file ids, they are given by position. After
+// compaction files are out of order, so the id of each file entry is kept
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodedDisk {
+    pub map: DiskMap,
+    pub ids: Vec<usize>,
+}
+
+impl DiskMap {
+    // Free spans of length 0 are left out
+    pub fn to_block_groups(&self) -> Vec<BlockGroup> {
+        self.lengths
+            .iter()
+            .enumerate()
+            .filter(|&(index, &length)| index % 2 == 0 || length != 0)
+            .map(|(index, &length)| BlockGroup {
+                id: (index % 2 == 0).then_some(index / 2),
+                length,
+            })
+            .collect()
+    }
+
+    // One entry per block, `None` for free blocks
+    pub fn to_blocks(&self) -> Vec<Option<usize>> {
+        self.to_block_groups()
+            .into_iter()
+            .flat_map(|block| (0..block.length).map(move |_| block.id))
+            .collect()
+    }
+}
+
+// Trailing whitespace, including `\r\n`, is ignored. The map may end with
+// either a file or a free span
+impl FromStr for DiskMap {
+    type Err = DiskMapError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim_end();
+        if input.is_empty() {
+            return Err(DiskMapError::Empty);
+        }
+        let lengths = input
+            .chars()
+            .enumerate()
+            .map(|(index, c)| {
+                c.to_digit(10)
+                    .map(|digit| digit as usize)
+                    .ok_or(DiskMapError::InvalidDigit { index, found: c })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(DiskMap { lengths })
+    }
+}
+
+// Only valid as a dense disk map if every length is a single digit, like
+// the maps from `encode_disk_map`
+impl fmt::Display for DiskMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for length in &self.lengths {
+            write!(f, "{length}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DiskMapError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DiskMapError::Empty => write!(f, "the disk map is empty"),
+            DiskMapError::InvalidDigit { index, found } => {
+                write!(f, "expected a digit at index {index}, found {found:?}")
+            }
+        }
+    }
+}
+
+impl Error for DiskMapError {}
+
+pub fn day9_part1(input: &str) -> usize {
+    let blocks = part1::read_blocks_from_string(input).unwrap();
+    let compacted_blocks = part1::compact_blocks(&blocks);
+    compacted_blocks
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, id)| Some(index * id?))
+        .sum()
+}
+
+pub fn day9_part2(input: &str) -> usize {
+    let blocks = part2::read_blocks_from_string(input).unwrap();
+    let compacted_blocks = part2::compact_blocks(blocks);
+    strategies::get_checksum(&compacted_blocks)
+}
+
+// Turns a block by block layout, like the one from `part1`, into groups
+pub fn group_blocks(blocks: &[Option<usize>]) -> Vec<BlockGroup> {
+    let mut groups: Vec<BlockGroup> = Vec::new();
+    for &id in blocks {
+        match groups.last_mut() {
+            Some(group) if group.id == id => group.length += 1,
+            _ => groups.push(BlockGroup { id, length: 1 }),
+        }
+    }
+    groups
+}
+
+// Adjacent groups with the same id are merged. Lengths above 9 are split in
+// several entries joined by empty entries: a free span of length 0 between
+// two parts of a file, or a file of length 0 between two free spans
+pub fn encode_disk_map(blocks: &[BlockGroup]) -> EncodedDisk {
+    let mut merged: Vec<BlockGroup> = Vec::new();
+    for &block in blocks.iter().filter(|block| block.length > 0) {
+        match merged.last_mut() {
+            Some(group) if group.id == block.id => group.length += block.length,
+            _ => merged.push(block),
+        }
+    }
+    let mut lengths = Vec::new();
+    let mut ids = Vec::new();
+    for block in merged {
+        let mut length = block.length;
+        match block.id {
+            None => {
+                if lengths.len() % 2 == 0 {
+                    lengths.push(0);
+                    ids.push(ids.last().copied().unwrap_or(0));
+                }
+                while length > 9 {
+                    lengths.extend([9, 0]);
+                    ids.push(ids.last().copied().unwrap_or(0));
+                    length -= 9;
+                }
+            }
+            Some(id) => {
+                if lengths.len() % 2 == 1 {
+                    lengths.push(0);
+                }
+                while length > 9 {
+                    lengths.extend([9, 0]);
+                    ids.push(id);
+                    length -= 9;
+                }
+                ids.push(id);
+            }
+        }
+        lengths.push(length);
+    }
+    EncodedDisk {
+        map: DiskMap { lengths },
+        ids,
+    }
+}
+
+// Draws every block like the puzzle statement, `.` for free blocks. Ids
+// above 9 are shown by their last digit
+pub fn render_blocks(blocks: &[BlockGroup]) -> String {
+    blocks
+        .iter()
+        .flat_map(|block| {
+            let c = match block.id {
+                None => '.',
+                Some(id) => char::from_digit((id % 10) as u32, 10).unwrap(),
+            };
+            (0..block.length).map(move |_| c)
+        })
+        .collect()
+}
+
+pub mod strategies {
+    use super::{group_blocks, part1, part2, BlockGroup};
+
+    pub trait CompactionStrategy {
+        fn name(&self) -> &str;
+
+        // The compacted blocks, keeping the disk length, and the number of
+        // moves it took
+        fn compact(&self, blocks: &[BlockGroup]) -> (Vec<BlockGroup>, usize);
+    }
+
+    // Moves single blocks from the end to the first free block, like part 1.
+    // Every block moved counts as a move
+    pub struct BlockByBlock;
+
+    impl CompactionStrategy for BlockByBlock {
+        fn name(&self) -> &str {
+            "block by block"
+        }
+
+        fn compact(&self, blocks: &[BlockGroup]) -> (Vec<BlockGroup>, usize) {
+            let blocks: Vec<_> = blocks
+                .iter()
+                .flat_map(|block| (0..block.length).map(move |_| block.id))
+                .collect();
+            let mut compacted = part1::compact_blocks(&blocks);
+            let moves = blocks
+                .iter()
+                .zip(&compacted)
+                .filter(|(before, after)| before != after)
+                .count();
+            compacted.resize(blocks.len(), None);
+            (group_blocks(&compacted), moves)
+        }
+    }
+
+    // Moves whole files from the end to a free span to their left, like
+    // part 2. Every file moved counts as a move
+    pub struct WholeFile(pub part2::Fit);
+
+    impl CompactionStrategy for WholeFile {
+        fn name(&self) -> &str {
+            match self.0 {
+                part2::Fit::First => "first fit",
+                part2::Fit::Best => "best fit",
+                part2::Fit::Worst => "worst fit",
+            }
+        }
+
+        fn compact(&self, blocks: &[BlockGroup]) -> (Vec<BlockGroup>, usize) {
+            part2::compact_files(blocks.to_vec(), self.0)
+        }
+    }
+
+    // Slides every file to the left, keeping their order, so all the free
+    // space ends up in a single span at the end
+    pub struct MoveToEnd;
+
+    impl CompactionStrategy for MoveToEnd {
+        fn name(&self) -> &str {
+            "move to end"
+        }
+
+        fn compact(&self, blocks: &[BlockGroup]) -> (Vec<BlockGroup>, usize) {
+            let mut compacted: Vec<BlockGroup> = Vec::new();
+            let mut free = 0;
+            let mut moves = 0;
+            for &block in blocks.iter().filter(|block| block.length > 0) {
+                if block.id.is_none() {
+                    free += block.length;
+                    continue;
+                }
+                if free > 0 {
+                    moves += 1;
+                }
+                match compacted.last_mut() {
+                    Some(group) if group.id == block.id => group.length += block.length,
+                    _ => compacted.push(block),
+                }
+            }
+            if free > 0 {
+                compacted.push(BlockGroup {
+                    id: None,
+                    length: free,
+                });
+            }
+            (compacted, moves)
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct CompactionStats {
+        // Contiguous pieces of files, a file that isn't split counts once
+        pub fragments: usize,
+        pub largest_free_span: usize,
+        pub moves: usize,
+        pub checksum: usize,
+    }
+
+    pub fn get_stats(strategy: &dyn CompactionStrategy, blocks: &[BlockGroup]) -> CompactionStats {
+        let (compacted, moves) = strategy.compact(blocks);
+        let merged = group_blocks(
+            &compacted
+                .iter()
+                .flat_map(|block| (0..block.length).map(move |_| block.id))
+                .collect::<Vec<_>>(),
+        );
+        CompactionStats {
+            fragments: merged.iter().filter(|block| block.id.is_some()).count(),
+            largest_free_span: merged
+                .iter()
+                .filter(|block| block.id.is_none())
+                .map(|block| block.length)
+                .max()
+                .unwrap_or(0),
+            moves,
+            checksum: get_checksum(&compacted),
+        }
+    }
+
+    pub fn get_checksum(blocks: &[BlockGroup]) -> usize {
+        blocks
+            .iter()
+            .flat_map(|block| (0..block.length).map(move |_| block.id))
+            .enumerate()
+            .filter_map(|(index, id)| Some(index * id?))
+            .sum()
+    }
+}
+
+pub mod part1 {
+    use super::{DiskMap, DiskMapError};
+
+    // Moves the last file block to the first free block until every file
+    // block is before every free one. The free blocks at the end are dropped
+    pub fn compact_blocks(blocks: &[Option<usize>]) -> Vec<Option<usize>> {
+        let mut compacted = blocks.to_vec();
+        let mut start_index = 0;
+        let mut end_index = compacted.len();
+        while start_index < end_index {
+            if compacted[start_index].is_some() {
+                start_index += 1;
+            } else if compacted[end_index - 1].is_none() {
+                end_index -= 1;
+            } else {
+                compacted.swap(start_index, end_index - 1);
+            }
+        }
+        compacted.truncate(end_index);
+        compacted
+    }
+
+    pub fn read_blocks_from_string(input: &str) -> Result<Vec<Option<usize>>, DiskMapError> {
+        Ok(input.parse::<DiskMap>()?.to_blocks())
+    }
+}
+
+pub mod part2 {
+    use super::{DiskMap, DiskMapError};
+    use std::{cmp::Reverse, collections::BinaryHeap};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct BlockGroup {
+        // `None` for free space
+        pub id: Option<usize>,
+        pub length: usize,
+    }
+
+    // Which of the free spans to the left of a file that fit it is used
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Fit {
+        // The leftmost one
+        First,
+        // The shortest one, the leftmost if tied
+        Best,
+        // The longest one, the leftmost if tied
+        Worst,
+    }
+
+    pub fn compact_blocks(blocks: Vec<BlockGroup>) -> Vec<BlockGroup> {
+        compact_files(blocks, Fit::First).0
+    }
+
+    // Keeps a min-heap of free span starts for every span length, so the
+    // leftmost span of each length is always at hand. Returns the compacted
+    // blocks and the number of files moved
+    pub fn compact_files(blocks: Vec<BlockGroup>, fit: Fit) -> (Vec<BlockGroup>, usize) {
+        let max_length = blocks.iter().map(|block| block.length).max().unwrap_or(0);
+        let mut free_spans = vec![BinaryHeap::new(); max_length + 1];
+        let mut files = Vec::new();
+        let mut start = 0;
+        for block in blocks {
+            if block.id.is_none() {
+                free_spans[block.length].push(Reverse(start));
+            } else {
+                files.push((start, block));
+            }
+            start += block.length;
+        }
+        let mut moves = 0;
+        for (file_start, file) in files.iter_mut().rev() {
+            if file.length == 0 {
+                continue;
+            }
+            let mut candidates = (file.length..free_spans.len())
+                .filter_map(|length| {
+                    let &Reverse(start) = free_spans[length].peek()?;
+                    Some((start, length))
+                })
+                .filter(|&(start, _)| start < *file_start);
+            let chosen = match fit {
+                Fit::First => candidates.min(),
+                Fit::Best => candidates.next(),
+                Fit::Worst => candidates.next_back(),
+            };
+            if let Some((start, length)) = chosen {
+                moves += 1;
+                free_spans[length].pop();
+                if length > file.length {
+                    free_spans[length - file.length].push(Reverse(start + file.length));
+                }
+                *file_start = start;
+            }
+        }
+        files.sort_by_key(|&(start, _)| start);
+        let disk_length = start;
+        let mut compacted = Vec::new();
+        let mut end = 0;
+        for (start, file) in files {
+            if start > end {
+                compacted.push(BlockGroup {
+                    id: None,
+                    length: start - end,
+                });
+            }
+            compacted.push(file);
+            end = start + file.length;
+        }
+        if disk_length > end {
+            compacted.push(BlockGroup {
+                id: None,
+                length: disk_length - end,
+            });
+        }
+        (compacted, moves)
+    }
+
+    pub fn read_blocks_from_string(input: &str) -> Result<Vec<BlockGroup>, DiskMapError> {
+        Ok(input.parse::<DiskMap>()?.to_block_groups())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn part1_correct_output_for_test_input() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let result = day9_part1(&contents);
+        assert_eq!(result, 1928);
+    }
+
+    #[test]
+    fn blocks_are_rendered_before_and_after_compaction() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let blocks = part2::read_blocks_from_string(&contents).unwrap();
+        assert_eq!(
+            render_blocks(&blocks),
+            "00...111...2...333.44.5555.6666.777.888899"
+        );
+        let compacted = part2::compact_blocks(blocks);
+        assert_eq!(
+            render_blocks(&compacted),
+            "00992111777.44.333....5555.6666.....8888.."
+        );
+        let compacted = part1::compact_blocks(&part1::read_blocks_from_string(&contents).unwrap());
+        assert_eq!(
+            render_blocks(&group_blocks(&compacted)),
+            "0099811188827773336446555566"
+        );
+    }
+
+    #[test]
+    fn compacted_disks_are_encoded_as_disk_maps() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let blocks = part2::read_blocks_from_string(&contents).unwrap();
+        let encoded = encode_disk_map(&blocks);
+        assert_eq!(encoded.map.to_string(), contents.trim());
+        assert_eq!(encoded.ids, (0..10).collect::<Vec<_>>());
+
+        let encoded = encode_disk_map(&part2::compact_blocks(blocks));
+        assert_eq!(encoded.map.to_string(), "20201030312134414542");
+        assert_eq!(encoded.ids, [0, 9, 2, 1, 7, 4, 3, 5, 6, 8]);
+
+        let blocks = [
+            BlockGroup {
+                id: Some(0),
+                length: 12,
+            },
+            BlockGroup {
+                id: None,
+                length: 3,
+            },
+            BlockGroup {
+                id: None,
+                length: 8,
+            },
+            BlockGroup {
+                id: Some(1),
+                length: 1,
+            },
+        ];
+        let encoded = encode_disk_map(&blocks);
+        assert_eq!(encoded.map.to_string(), "9039021");
+        assert_eq!(encoded.ids, [0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn strategies_report_their_stats() {
+        use strategies::*;
+
+        let contents = fs::read_to_string("test_input").unwrap();
+        let blocks = part2::read_blocks_from_string(&contents).unwrap();
+        let stats = |strategy: &dyn CompactionStrategy| get_stats(strategy, &blocks);
+        assert_eq!(
+            stats(&BlockByBlock),
+            CompactionStats {
+                fragments: 13,
+                largest_free_span: 14,
+                moves: 12,
+                checksum: 1928
+            }
+        );
+        assert_eq!(
+            stats(&WholeFile(part2::Fit::First)),
+            CompactionStats {
+                fragments: 10,
+                largest_free_span: 5,
+                moves: 4,
+                checksum: 2858
+            }
+        );
+        let best = stats(&WholeFile(part2::Fit::Best));
+        let worst = stats(&WholeFile(part2::Fit::Worst));
+        assert_eq!((best.fragments, worst.fragments), (10, 10));
+        assert_eq!((best.checksum, worst.checksum), (2858, 2858));
+
+        let blocks = part2::read_blocks_from_string("1312111").unwrap();
+        let render = |fit| render_blocks(&WholeFile(fit).compact(&blocks).0);
+        assert_eq!(render(part2::Fit::First), "0321......");
+        assert_eq!(render(part2::Fit::Best), "01...2..3.");
+        assert_eq!(render(part2::Fit::Worst), "0321......");
+        assert_eq!(
+            stats(&MoveToEnd),
+            CompactionStats {
+                fragments: 10,
+                largest_free_span: 14,
+                moves: 9,
+                checksum: (0..10)
+                    .flat_map(|id| vec![id; [2, 3, 1, 3, 2, 4, 4, 3, 4, 2][id]])
+                    .enumerate()
+                    .map(|(index, id)| index * id)
+                    .sum()
+            }
+        );
+    }
+
+    #[test]
+    fn disk_maps_are_validated() {
+        let map: DiskMap = "12345\r\n".parse().unwrap();
+        assert_eq!(map.lengths, [1, 2, 3, 4, 5]);
+        assert_eq!(render_blocks(&map.to_block_groups()), "0..111....22222");
+        assert_eq!("".parse::<DiskMap>(), Err(DiskMapError::Empty));
+        assert_eq!(
+            "12a4".parse::<DiskMap>(),
+            Err(DiskMapError::InvalidDigit {
+                index: 2,
+                found: 'a'
+            })
+        );
+        assert_eq!(
+            "1 2".parse::<DiskMap>().unwrap_err().to_string(),
+            "expected a digit at index 1, found ' '"
+        );
+        let blocks = vec![Some(usize::MAX), None, Some(0)];
+        assert_eq!(part1::compact_blocks(&blocks), [Some(usize::MAX), Some(0)]);
+        let blocks = part1::read_blocks_from_string("19").unwrap();
+        assert_eq!(part1::compact_blocks(&blocks), [Some(0)]);
+    }
+
+    #[test]
+    fn part1_correct_output_for_input() {
+        let contents = fs::read_to_string("input").unwrap();
+        let result = day9_part1(&contents);
+        assert_eq!(result, 6398252054886);
+    }
+
+    #[test]
+    fn part2_correct_output_for_test_input() {
+        let contents = fs::read_to_string("test_input").unwrap();
+        let result = day9_part2(&contents);
+        assert_eq!(result, 2858);
+    }
+
+    #[test]
+    fn part2_correct_output_for_input() {
+        let contents = fs::read_to_string("input").unwrap();
+        let result = day9_part2(&contents);
+        assert_eq!(result, 6415666220005);
+    }
+}